@@ -0,0 +1,221 @@
+//!
+//! Decimation of long sample series for drawing.
+//!
+//! [min_max_columns] partitions the samples into columns. Sample i
+//! goes to column `i.map_range((0, n), (0, width))` rounded down, so
+//! every sample lands in exactly one column. Each column gives
+//! min, max, first and last of its samples, which can be mapped into
+//! the pixel/row range with [Column::map_range].
+//!
+//! [lttb] selects a subset of the samples with the
+//! largest-triangle-three-buckets algorithm, which keeps the
+//! visual shape of the series.
+//!
+//! ```rust
+//! use map_range_int::decimate::{lttb, min_max_columns, Column};
+//!
+//! let samples = [3u16, 9, 1, 4, 7, 7, 2, 8];
+//! let cols: Vec<_> = min_max_columns(&samples, 3).collect();
+//! assert_eq!(
+//!     cols[0],
+//!     Some(Column { min: 1, max: 9, first: 3, last: 1 })
+//! );
+//!
+//! // rows for a 10 row high chart
+//! let rows = cols[1].expect("column").map_range((0, 9), (0u8, 9));
+//! assert_eq!(rows, Some(Column { min: 4, max: 7, first: 4, last: 7 }));
+//!
+//! let samples = [0., 1., 0., 5., 0., 1., 0.];
+//! let mut idx = [0; 4];
+//! let n = lttb(&samples, &mut idx);
+//! assert_eq!(&idx[..n], &[0, 3, 4, 6]);
+//! ```
+//!
+
+use crate::MapRange;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+/// Samples in column c, when n samples are split into width
+/// columns. Columns are empty if width > n.
+///
+/// Column c starts at the first sample i with
+/// `floor(i * width / n) >= c`.
+pub fn column_samples(c: usize, n: usize, width: usize) -> Range<usize> {
+    if width == 0 {
+        return 0..0;
+    }
+    let c = c.min(width);
+    // ceil(c * n / width) == n - floor((width - c) * n / width)
+    let start = |c: usize| n - (width - c).map_range_unchecked((0, width), (0, n));
+    start(c)..start((c + 1).min(width))
+}
+
+/// Samples of one column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Column<T> {
+    /// Smallest sample.
+    pub min: T,
+    /// Largest sample.
+    pub max: T,
+    /// First sample.
+    pub first: T,
+    /// Last sample.
+    pub last: T,
+}
+
+impl<T> Column<T>
+where
+    T: Copy + PartialOrd,
+{
+    /// Map all values from range to o_range.
+    ///
+    /// Returns None if a value is out of bounds for range or
+    /// o_range is reversed.
+    pub fn map_range<U>(&self, range: (T, T), o_range: (U, U)) -> Option<Column<U>>
+    where
+        T: MapRange<U>,
+        U: PartialOrd + Copy,
+    {
+        Some(Column {
+            min: self.min.map_range(range, o_range)?,
+            max: self.max.map_range(range, o_range)?,
+            first: self.first.map_range(range, o_range)?,
+            last: self.last.map_range(range, o_range)?,
+        })
+    }
+}
+
+/// Min, max, first and last for each of width columns.
+///
+/// Yields width items, empty columns are None.
+pub fn min_max_columns<T>(samples: &[T], width: usize) -> MinMaxColumns<'_, T>
+where
+    T: Copy + PartialOrd,
+{
+    MinMaxColumns {
+        samples,
+        width,
+        c: 0,
+    }
+}
+
+/// Iterator for [min_max_columns].
+#[derive(Debug, Clone)]
+pub struct MinMaxColumns<'a, T> {
+    samples: &'a [T],
+    width: usize,
+    c: usize,
+}
+
+impl<T> Iterator for MinMaxColumns<'_, T>
+where
+    T: Copy + PartialOrd,
+{
+    type Item = Option<Column<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.c >= self.width {
+            return None;
+        }
+        let r = column_samples(self.c, self.samples.len(), self.width);
+        self.c += 1;
+
+        let s = &self.samples[r];
+        let (&first, rest) = match s.split_first() {
+            Some(v) => v,
+            None => return Some(None),
+        };
+        let mut col = Column {
+            min: first,
+            max: first,
+            first,
+            last: first,
+        };
+        for &v in rest {
+            if v < col.min {
+                col.min = v;
+            }
+            if v > col.max {
+                col.max = v;
+            }
+            col.last = v;
+        }
+        Some(Some(col))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.width - self.c;
+        (len, Some(len))
+    }
+}
+
+impl<T: Copy + PartialOrd> ExactSizeIterator for MinMaxColumns<'_, T> {}
+
+impl<T: Copy + PartialOrd> FusedIterator for MinMaxColumns<'_, T> {}
+
+/// Largest-triangle-three-buckets downsampling.
+///
+/// Selects `out.len()` samples and writes their indices to out.
+/// The first and the last sample are always selected, the
+/// samples in between are split into buckets like
+/// [column_samples] and one sample is selected from each.
+///
+/// If there are not more samples than out.len() all indices are
+/// written. Returns the number of indices written.
+pub fn lttb<T>(samples: &[T], out: &mut [usize]) -> usize
+where
+    T: Copy + Into<f64>,
+{
+    let n = samples.len();
+    let k = out.len();
+    if n <= k {
+        for (i, v) in out.iter_mut().enumerate().take(n) {
+            *v = i;
+        }
+        return n;
+    }
+    match k {
+        0 => return 0,
+        1 => {
+            out[0] = 0;
+            return 1;
+        }
+        _ => {}
+    }
+
+    let y = |i: usize| -> f64 { samples[i].into() };
+    let buckets = k - 2;
+    let bucket = |b: usize| {
+        let r = column_samples(b, n - 2, buckets);
+        r.start + 1..r.end + 1
+    };
+
+    let mut a = 0;
+    out[0] = 0;
+    for b in 0..buckets {
+        let next = if b + 1 < buckets {
+            bucket(b + 1)
+        } else {
+            n - 1..n
+        };
+        let len = next.len() as f64;
+        let avg_x = next.clone().map(|i| i as f64).sum::<f64>() / len;
+        let avg_y = next.map(y).sum::<f64>() / len;
+
+        let (ax, ay) = (a as f64, y(a));
+        let mut best = 0.0;
+        let mut best_i = usize::MAX;
+        for i in bucket(b) {
+            let area = ((ax - avg_x) * (y(i) - ay) - (ax - i as f64) * (avg_y - ay)).abs();
+            if best_i == usize::MAX || area > best {
+                best = area;
+                best_i = i;
+            }
+        }
+        out[b + 1] = best_i;
+        a = best_i;
+    }
+    out[k - 1] = n - 1;
+    k
+}
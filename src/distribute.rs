@@ -0,0 +1,286 @@
+//!
+//! Distribute an integer total across slots.
+//!
+//! The parts always sum up to the total exactly.
+//!
+//! * [distribute] splits equally. Part i ends at
+//!   `(i + 1).map_range((0, n), (0, total))`, so the boundaries are
+//!   the same as with [MapRange] and the larger parts are spread out
+//!   evenly.
+//! * [distribute_weighted] splits by weights with the largest-remainder
+//!   method. Each part gets the floor of its share, the rest goes
+//!   to the parts with the largest remainders, ties go to the lower
+//!   index.
+//!
+//! All calculations use u128, so they can't overflow.
+//!
+//! The inverse, which part contains a position, is [index_at] for
+//! equal splits and [index_at_ends] for anything else.
+//!
+//! ```rust
+//! use map_range_int::distribute::{distribute, distribute_weighted, index_at, index_at_ends};
+//!
+//! let cols: Vec<u16> = distribute(80u16, 6).collect();
+//! assert_eq!(cols, vec![13, 13, 14, 13, 13, 14]);
+//!
+//! let parts: Vec<u64> = distribute_weighted(100u64, &[1, 1, 1]).collect();
+//! assert_eq!(parts, vec![34, 33, 33]);
+//!
+//! // fill a slice
+//! let mut widths = [0u32; 3];
+//! for (w, v) in widths.iter_mut().zip(distribute_weighted(10, &[2, 5, 3])) {
+//!     *w = v;
+//! }
+//! assert_eq!(widths, [2, 5, 3]);
+//!
+//! // hit testing
+//! assert_eq!(index_at(26u16, 6, 80), Some((2, 0)));
+//! assert_eq!(index_at(39u16, 6, 80), Some((2, 13)));
+//!
+//! let ends = [2u32, 7, 10];
+//! assert_eq!(index_at_ends(6, &ends), Some((1, 4)));
+//! ```
+//!
+
+use crate::MapRange;
+use core::iter::FusedIterator;
+
+/// Values that can be distributed.
+pub trait DistributeValue: Copy + PartialOrd {
+    /// Widen to u128.
+    fn to_wide(self) -> u128;
+
+    /// Narrow from u128. Only called with values <= the total.
+    fn from_wide(v: u128) -> Self;
+}
+
+macro_rules! distribute_value {
+    ($ty:ty) => {
+        impl DistributeValue for $ty {
+            #[inline(always)]
+            fn to_wide(self) -> u128 {
+                self as u128
+            }
+
+            #[inline(always)]
+            fn from_wide(v: u128) -> Self {
+                v as $ty
+            }
+        }
+    };
+}
+
+distribute_value!(u8);
+distribute_value!(u16);
+distribute_value!(u32);
+distribute_value!(u64);
+distribute_value!(usize);
+
+/// Split total into n parts that differ by at most 1.
+///
+/// Yields nothing for n == 0.
+pub fn distribute<T>(total: T, n: usize) -> Distribute<T>
+where
+    T: DistributeValue,
+    usize: MapRange<T>,
+{
+    Distribute { total, n, i: 0 }
+}
+
+/// Equal split, see [distribute].
+#[derive(Debug, Clone)]
+pub struct Distribute<T> {
+    total: T,
+    n: usize,
+    i: usize,
+}
+
+impl<T> Iterator for Distribute<T>
+where
+    T: DistributeValue,
+    usize: MapRange<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.n {
+            return None;
+        }
+        let o_range = (T::from_wide(0), self.total);
+        let start: T = self.i.map_range_unchecked((0, self.n), o_range);
+        let end: T = (self.i + 1).map_range_unchecked((0, self.n), o_range);
+        self.i += 1;
+        Some(T::from_wide(end.to_wide() - start.to_wide()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.n - self.i;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Distribute<T>
+where
+    T: DistributeValue,
+    usize: MapRange<T>,
+{
+}
+
+impl<T> FusedIterator for Distribute<T>
+where
+    T: DistributeValue,
+    usize: MapRange<T>,
+{
+}
+
+/// Split total by weights with the largest-remainder method.
+///
+/// Yields one part per weight. If all weights are 0 the total is
+/// split equally as with [distribute].
+///
+/// Needs no allocation, the remainders are ranked with a binary
+/// search over the weights, O(n log total).
+pub fn distribute_weighted<T>(total: T, weights: &[T]) -> DistributeWeighted<'_, T>
+where
+    T: DistributeValue,
+{
+    let t = total.to_wide();
+    let sum: u128 = weights.iter().map(|w| w.to_wide()).sum();
+
+    let (threshold, ties) = if sum == 0 {
+        (0, 0)
+    } else {
+        let rest = t - weights.iter().map(|w| t * w.to_wide() / sum).sum::<u128>();
+        if rest == 0 {
+            (sum, 0)
+        } else {
+            let rest = rest as usize;
+            let count = |th: u128| {
+                weights
+                    .iter()
+                    .filter(|w| (t * w.to_wide()) % sum >= th)
+                    .count()
+            };
+            // largest threshold with count(rem >= threshold) >= rest
+            let mut lo = 0u128;
+            let mut hi = sum - 1;
+            while lo < hi {
+                let mid = lo + (hi - lo).div_ceil(2);
+                if count(mid) >= rest {
+                    lo = mid;
+                } else {
+                    hi = mid - 1;
+                }
+            }
+            let above = count(lo + 1);
+            (lo, rest - above)
+        }
+    };
+
+    DistributeWeighted {
+        total: t,
+        weights,
+        sum,
+        threshold,
+        ties,
+        i: 0,
+    }
+}
+
+/// Weighted split, see [distribute_weighted].
+#[derive(Debug, Clone)]
+pub struct DistributeWeighted<'a, T> {
+    total: u128,
+    weights: &'a [T],
+    sum: u128,
+    /// Remainders above get one more.
+    threshold: u128,
+    /// Remainders equal to threshold that still get one more.
+    ties: usize,
+    i: usize,
+}
+
+impl<T> Iterator for DistributeWeighted<'_, T>
+where
+    T: DistributeValue,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.weights.len();
+        if self.i >= n {
+            return None;
+        }
+        let i = self.i as u128;
+        self.i += 1;
+
+        if self.sum == 0 {
+            let start = i * self.total / n as u128;
+            let end = (i + 1) * self.total / n as u128;
+            return Some(T::from_wide(end - start));
+        }
+
+        let v = self.total * self.weights[i as usize].to_wide();
+        let (part, rem) = (v / self.sum, v % self.sum);
+        let extra = if rem > self.threshold {
+            1
+        } else if rem == self.threshold && self.ties > 0 {
+            self.ties -= 1;
+            1
+        } else {
+            0
+        };
+        Some(T::from_wide(part + extra))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.weights.len() - self.i;
+        (len, Some(len))
+    }
+}
+
+impl<T: DistributeValue> ExactSizeIterator for DistributeWeighted<'_, T> {}
+
+impl<T: DistributeValue> FusedIterator for DistributeWeighted<'_, T> {}
+
+/// Part of an equal split that contains pos, and the offset of
+/// pos within the part. The inverse of [distribute], with the same
+/// boundaries. Empty parts are never hit. O(1).
+///
+/// Returns None if pos >= extent or n == 0.
+pub fn index_at<T>(pos: T, n: usize, extent: T) -> Option<(usize, T)>
+where
+    T: DistributeValue + MapRange<usize>,
+    usize: MapRange<T>,
+{
+    if n == 0 || pos >= extent {
+        return None;
+    }
+    // last part that starts at or before pos
+    // ceil((pos + 1) * n / extent) - 1
+    let rev = T::from_wide(extent.to_wide() - pos.to_wide() - 1);
+    let rev: usize = rev.map_range_unchecked((T::from_wide(0), extent), (0, n));
+    let idx = n - rev - 1;
+    let start: T = idx.map_range_unchecked((0, n), (T::from_wide(0), extent));
+    Some((idx, T::from_wide(pos.to_wide() - start.to_wide())))
+}
+
+/// Part that contains pos, and the offset of pos within the part.
+/// O(log n).
+///
+/// ends contains the exclusive end of each part, which is the
+/// running sum of the part sizes. It must be non-decreasing.
+/// Empty parts are never hit.
+///
+/// Returns None if pos is beyond the last end.
+pub fn index_at_ends<T>(pos: T, ends: &[T]) -> Option<(usize, T)>
+where
+    T: DistributeValue,
+{
+    let idx = ends.partition_point(|v| *v <= pos);
+    if idx == ends.len() {
+        return None;
+    }
+    let start = if idx == 0 { 0 } else { ends[idx - 1].to_wide() };
+    Some((idx, T::from_wide(pos.to_wide() - start)))
+}
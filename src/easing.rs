@@ -0,0 +1,460 @@
+//!
+//! Easing functions.
+//!
+//! [MapRangeEased] maps the source range to 0..1, applies an [Ease]
+//! and maps the result onto the target range. Made for animations that
+//! map elapsed time onto some position.
+//!
+//! Float targets use f64. Integer targets use a 16.16 fixed-point
+//! calculation that doesn't need an FPU.
+//!
+//! ```rust
+//! use map_range_int::easing::{Ease, MapRangeEased};
+//!
+//! let x: u16 = 0u32
+//!     .map_range_eased((0, 500), (20, 220), Ease::CubicInOut)
+//!     .expect("in_range");
+//! assert_eq!(x, 20);
+//! let x: u16 = 125u32
+//!     .map_range_eased((0, 500), (20, 220), Ease::CubicInOut)
+//!     .expect("in_range");
+//! assert_eq!(x, 32);
+//! let x: u16 = 500u32
+//!     .map_range_eased((0, 500), (20, 220), Ease::CubicInOut)
+//!     .expect("in_range");
+//! assert_eq!(x, 220);
+//!
+//! // runs backwards too.
+//! let x: f32 = 250u32
+//!     .map_range_eased((0, 500), (1., 0.), Ease::QuadOut)
+//!     .expect("in_range");
+//! assert_eq!(x, 0.25);
+//! ```
+//!
+
+use crate::fmath;
+use crate::log::exp2_q32;
+use crate::MapRange;
+
+/// One as 16.16 fixed-point.
+const Q: i64 = 1 << 16;
+
+/// Easing curves.
+///
+/// The curves follow the usual definitions from easings.net.
+/// All of them start at 0 and end at 1, Back and Elastic overshoot
+/// in between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ease {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    /// 3t² - 2t³
+    SmoothStep,
+    /// 6t⁵ - 15t⁴ + 10t³
+    SmootherStep,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// CSS cubic-bezier(x1, y1, x2, y2) with the control points
+    /// as 16.16 fixed-point. See [Ease::cubic_bezier].
+    CubicBezier(i32, i32, i32, i32),
+}
+
+impl Ease {
+    /// CSS cubic-bezier(x1, y1, x2, y2).
+    ///
+    /// x1 and x2 are clamped to 0..=1 to keep the curve a function.
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Ease {
+        fn q16(v: f32) -> i32 {
+            (v * 65536.0) as i32
+        }
+        Ease::CubicBezier(
+            q16(x1.clamp(0.0, 1.0)),
+            q16(y1),
+            q16(x2.clamp(0.0, 1.0)),
+            q16(y2),
+        )
+    }
+
+    /// Apply the easing to t in 0..=1.
+    pub fn apply(self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        if t >= 1.0 {
+            return 1.0;
+        }
+
+        const C1: f64 = 1.70158;
+        const C2: f64 = C1 * 1.525;
+        const C3: f64 = C1 + 1.0;
+        const C4: f64 = 2.0 * core::f64::consts::PI / 3.0;
+        const C5: f64 = 2.0 * core::f64::consts::PI / 4.5;
+
+        fn exp2(x: f64) -> f64 {
+            fmath::exp(x * core::f64::consts::LN_2)
+        }
+        fn bounce_out(t: f64) -> f64 {
+            const N1: f64 = 7.5625;
+            const D1: f64 = 2.75;
+            if t < 1.0 / D1 {
+                N1 * t * t
+            } else if t < 2.0 / D1 {
+                let t = t - 1.5 / D1;
+                N1 * t * t + 0.75
+            } else if t < 2.5 / D1 {
+                let t = t - 2.25 / D1;
+                N1 * t * t + 0.9375
+            } else {
+                let t = t - 2.625 / D1;
+                N1 * t * t + 0.984375
+            }
+        }
+
+        match self {
+            Ease::Linear => t,
+            Ease::QuadIn => t * t,
+            Ease::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Ease::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    let u = 2.0 - 2.0 * t;
+                    1.0 - u * u / 2.0
+                }
+            }
+            Ease::CubicIn => t * t * t,
+            Ease::CubicOut => {
+                let u = 1.0 - t;
+                1.0 - u * u * u
+            }
+            Ease::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let u = 2.0 - 2.0 * t;
+                    1.0 - u * u * u / 2.0
+                }
+            }
+            Ease::SmoothStep => t * t * (3.0 - 2.0 * t),
+            Ease::SmootherStep => t * t * t * (t * (6.0 * t - 15.0) + 10.0),
+            Ease::BackIn => t * t * (C3 * t - C1),
+            Ease::BackOut => {
+                let u = t - 1.0;
+                1.0 + u * u * (C3 * u + C1)
+            }
+            Ease::BackInOut => {
+                if t < 0.5 {
+                    let v = 2.0 * t;
+                    v * v * ((C2 + 1.0) * v - C2) / 2.0
+                } else {
+                    let v = 2.0 * t - 2.0;
+                    (v * v * ((C2 + 1.0) * v + C2) + 2.0) / 2.0
+                }
+            }
+            Ease::ElasticIn => -exp2(10.0 * t - 10.0) * fmath::sin((10.0 * t - 10.75) * C4),
+            Ease::ElasticOut => exp2(-10.0 * t) * fmath::sin((10.0 * t - 0.75) * C4) + 1.0,
+            Ease::ElasticInOut => {
+                let s = fmath::sin((20.0 * t - 11.125) * C5);
+                if t < 0.5 {
+                    -(exp2(20.0 * t - 10.0) * s) / 2.0
+                } else {
+                    exp2(-20.0 * t + 10.0) * s / 2.0 + 1.0
+                }
+            }
+            Ease::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Ease::BounceOut => bounce_out(t),
+            Ease::BounceInOut => {
+                if t < 0.5 {
+                    (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0
+                } else {
+                    (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0
+                }
+            }
+            Ease::CubicBezier(x1, y1, x2, y2) => {
+                let x1 = x1 as f64 / 65536.0;
+                let y1 = y1 as f64 / 65536.0;
+                let x2 = x2 as f64 / 65536.0;
+                let y2 = y2 as f64 / 65536.0;
+                let bezier = |p1: f64, p2: f64, s: f64| {
+                    let u = 1.0 - s;
+                    3.0 * u * u * s * p1 + 3.0 * u * s * s * p2 + s * s * s
+                };
+
+                // x(s) is monotone, bisect for x(s) == t
+                let (mut lo, mut hi) = (0.0, 1.0);
+                for _ in 0..52 {
+                    let mid = (lo + hi) / 2.0;
+                    if bezier(x1, x2, mid) < t {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                bezier(y1, y2, (lo + hi) / 2.0)
+            }
+        }
+    }
+
+    /// Apply the easing to t in 0..=65536 as 16.16 fixed-point.
+    ///
+    /// Doesn't use any float math.
+    pub fn apply_q16(self, t: i32) -> i32 {
+        let t = t as i64;
+        if t <= 0 {
+            return 0;
+        }
+        if t >= Q {
+            return Q as i32;
+        }
+
+        // 1.70158, 1.70158 * 1.525
+        const C1: i64 = 111515;
+        const C2: i64 = 170061;
+        const C3: i64 = C1 + Q;
+
+        #[inline]
+        fn mul(a: i64, b: i64) -> i64 {
+            (a * b) >> 16
+        }
+        // 2^(10s - 10) for s in 0..=1
+        fn exp2m10(s: i64) -> i64 {
+            exp2_q32(((10 * s) << 16) as u64 + (6 << 32)) as i64
+        }
+        fn bounce_out(t: i64) -> i64 {
+            // 7.5625 = 121/16, 2.75 = 11/4
+            let sq = |v: i64| (v * v) / (16 * Q);
+            if 11 * t < 4 * Q {
+                sq(11 * t)
+            } else if 11 * t < 8 * Q {
+                sq(11 * t - 6 * Q) + Q * 3 / 4
+            } else if 11 * t < 10 * Q {
+                sq(11 * t - 9 * Q) + Q * 15 / 16
+            } else {
+                sq(11 * t - Q * 21 / 2) + Q * 63 / 64
+            }
+        }
+
+        let r = match self {
+            Ease::Linear => t,
+            Ease::QuadIn => mul(t, t),
+            Ease::QuadOut => Q - mul(Q - t, Q - t),
+            Ease::QuadInOut => {
+                if t < Q / 2 {
+                    2 * mul(t, t)
+                } else {
+                    let u = 2 * Q - 2 * t;
+                    Q - mul(u, u) / 2
+                }
+            }
+            Ease::CubicIn => mul(mul(t, t), t),
+            Ease::CubicOut => {
+                let u = Q - t;
+                Q - mul(mul(u, u), u)
+            }
+            Ease::CubicInOut => {
+                if t < Q / 2 {
+                    4 * mul(mul(t, t), t)
+                } else {
+                    let u = 2 * Q - 2 * t;
+                    Q - mul(mul(u, u), u) / 2
+                }
+            }
+            Ease::SmoothStep => mul(mul(t, t), 3 * Q - 2 * t),
+            Ease::SmootherStep => mul(mul(mul(t, t), t), mul(t, 6 * t - 15 * Q) + 10 * Q),
+            Ease::BackIn => mul(mul(t, t), mul(C3, t) - C1),
+            Ease::BackOut => {
+                let u = t - Q;
+                Q + mul(mul(u, u), mul(C3, u) + C1)
+            }
+            Ease::BackInOut => {
+                if t < Q / 2 {
+                    let v = 2 * t;
+                    mul(mul(v, v), mul(C2 + Q, v) - C2) / 2
+                } else {
+                    let v = 2 * t - 2 * Q;
+                    (mul(mul(v, v), mul(C2 + Q, v) + C2) + 2 * Q) / 2
+                }
+            }
+            Ease::ElasticIn => {
+                // sin((10t - 10.75) * 2pi/3)
+                let s = sin_turns_q16((10 * t - Q * 43 / 4) / 3);
+                -mul(exp2m10(t), s)
+            }
+            Ease::ElasticOut => {
+                let s = sin_turns_q16((10 * t - Q * 3 / 4) / 3);
+                mul(exp2m10(Q - t), s) + Q
+            }
+            Ease::ElasticInOut => {
+                // sin((20t - 11.125) * 2pi/4.5)
+                let s = sin_turns_q16((20 * t - Q * 89 / 8) * 2 / 9);
+                if t < Q / 2 {
+                    -mul(exp2m10(2 * t), s) / 2
+                } else {
+                    mul(exp2m10(2 * Q - 2 * t), s) / 2 + Q
+                }
+            }
+            Ease::BounceIn => Q - bounce_out(Q - t),
+            Ease::BounceOut => bounce_out(t),
+            Ease::BounceInOut => {
+                if t < Q / 2 {
+                    (Q - bounce_out(Q - 2 * t)) / 2
+                } else {
+                    (Q + bounce_out(2 * t - Q)) / 2
+                }
+            }
+            Ease::CubicBezier(x1, y1, x2, y2) => {
+                let bezier = |p1: i64, p2: i64, s: i64| {
+                    let u = Q - s;
+                    3 * mul(mul(mul(u, u), s), p1)
+                        + 3 * mul(mul(mul(u, s), s), p2)
+                        + mul(mul(s, s), s)
+                };
+
+                // x(s) is monotone, bisect for x(s) == t
+                let (mut lo, mut hi) = (0, Q);
+                while hi - lo > 1 {
+                    let mid = (lo + hi) / 2;
+                    if bezier(x1 as i64, x2 as i64, mid) < t {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                bezier(y1 as i64, y2 as i64, hi)
+            }
+        };
+        r as i32
+    }
+}
+
+/// sin(2pi x) for x as 16.16 fixed-point turns.
+fn sin_turns_q16(x: i64) -> i64 {
+    // sin(pi/2 u) = u(a - u²(b - u²(c - d u²))) for u in 0..=1
+    const A: i64 = 102944;
+    const B: i64 = 42334;
+    const C: i64 = 5223;
+    const D: i64 = 307;
+
+    let mut z = x.rem_euclid(Q);
+    let mut sign = 1;
+    if z >= Q / 2 {
+        z -= Q / 2;
+        sign = -1;
+    }
+    if z > Q / 4 {
+        z = Q / 2 - z;
+    }
+    let u = 4 * z;
+    let u2 = (u * u) >> 16;
+    let p = A - ((u2 * (B - ((u2 * (C - ((D * u2) >> 16))) >> 16))) >> 16);
+    sign * ((u * p) >> 16)
+}
+
+/// Map ranges to ranges via an easing curve.
+pub trait MapRangeEased<Out>
+where
+    Self: PartialOrd + Sized,
+    Out: PartialOrd,
+{
+    /// Map from a source range to a target range and apply
+    /// the easing in between.
+    ///
+    /// Returns None if self is out of bounds for range.
+    ///
+    /// Unlike map_range() the target range can be reversed, an
+    /// animation can run in both directions. Back and Elastic can
+    /// overshoot the target range, for integer targets this
+    /// saturates at the limits of the type.
+    fn map_range_eased(self, range: (Self, Self), o_range: (Out, Out), ease: Ease) -> Option<Out>;
+}
+
+macro_rules! f_map_range_eased {
+    ($tgt_ty:ty) => {
+        impl<T> MapRangeEased<$tgt_ty> for T
+        where
+            T: MapRange<f64>,
+        {
+            fn map_range_eased(
+                self,
+                range: (T, T),
+                o_range: ($tgt_ty, $tgt_ty),
+                ease: Ease,
+            ) -> Option<$tgt_ty> {
+                if self < range.0 || self > range.1 {
+                    return None;
+                }
+                if self == range.0 {
+                    return Some(o_range.0);
+                }
+                if self == range.1 {
+                    return Some(o_range.1);
+                }
+
+                let t: f64 = self.map_range_unchecked(range, (0., 1.));
+                let e = ease.apply(t);
+                let o0 = o_range.0 as f64;
+                let o1 = o_range.1 as f64;
+                Some((o0 + (o1 - o0) * e) as $tgt_ty)
+            }
+        }
+    };
+}
+
+f_map_range_eased!(f64);
+f_map_range_eased!(f32);
+
+macro_rules! i_map_range_eased {
+    ($tgt_ty:ty) => {
+        impl<T> MapRangeEased<$tgt_ty> for T
+        where
+            T: MapRange<u32>,
+        {
+            fn map_range_eased(
+                self,
+                range: (T, T),
+                o_range: ($tgt_ty, $tgt_ty),
+                ease: Ease,
+            ) -> Option<$tgt_ty> {
+                if self < range.0 || self > range.1 {
+                    return None;
+                }
+                if self == range.0 {
+                    return Some(o_range.0);
+                }
+                if self == range.1 {
+                    return Some(o_range.1);
+                }
+
+                let t: u32 = self.map_range_unchecked(range, (0, Q as u32));
+                let e = ease.apply_q16(t as i32) as i128;
+                let o0 = o_range.0 as i128;
+                let o1 = o_range.1 as i128;
+                let v = o0 + (((o1 - o0) * e) >> 16);
+                Some(v.clamp(<$tgt_ty>::MIN as i128, <$tgt_ty>::MAX as i128) as $tgt_ty)
+            }
+        }
+    };
+}
+
+i_map_range_eased!(u8);
+i_map_range_eased!(u16);
+i_map_range_eased!(u32);
+i_map_range_eased!(u64);
+i_map_range_eased!(usize);
+i_map_range_eased!(i8);
+i_map_range_eased!(i16);
+i_map_range_eased!(i32);
+i_map_range_eased!(i64);
+i_map_range_eased!(isize);
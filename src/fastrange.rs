@@ -0,0 +1,60 @@
+//!
+//! Map a full-width hash onto 0..n without a division.
+//!
+//! `(h * n) >> bits` is `map_range` with a source span of 2^bits,
+//! which turns the division into a shift. The result is
+//! `floor(h * n / 2^bits)`, so
+//!
+//! * the result is < n for any n > 0, and 0 for n == 0.
+//! * the buckets are contiguous ranges of hashes. Bucket k gets the
+//!   hashes from `ceil(k * 2^bits / n)` up to the start of bucket k + 1,
+//!   the bucket sizes differ by at most one.
+//! * the order of the hashes is kept, and only the high bits of the
+//!   hash decide the bucket. A hash with weak high bits gives weak
+//!   buckets, unlike `h % n`.
+//!
+//! `h.map_range((0, u32::MAX), (0, n - 1))` instead divides by
+//! 2^bits - 1, and the last bucket gets only u32::MAX. With o_range
+//! (0, n) the last bucket gets n and the result can be out of bounds.
+//!
+//! ```rust
+//! use map_range_int::fastrange::{fastrange32, fastrange64, fastrange_slice};
+//!
+//! assert_eq!(fastrange32(0, 10), 0);
+//! assert_eq!(fastrange32(u32::MAX, 10), 9);
+//! assert_eq!(fastrange32(1 << 31, 10), 5);
+//! assert_eq!(fastrange64(u64::MAX / 3, 3), 0);
+//! assert_eq!(fastrange64(u64::MAX / 3 + 1, 3), 1);
+//!
+//! let mut shard = [0; 3];
+//! fastrange_slice(&[1, 1 << 62, u64::MAX], 4, &mut shard);
+//! assert_eq!(shard, [0, 1, 3]);
+//! ```
+//!
+
+/// floor(h * n / 2^32).
+#[inline(always)]
+pub const fn fastrange32(h: u32, n: u32) -> u32 {
+    ((h as u64 * n as u64) >> 32) as u32
+}
+
+/// floor(h * n / 2^64).
+#[inline(always)]
+pub const fn fastrange64(h: u64, n: u64) -> u64 {
+    ((h as u128 * n as u128) >> 64) as u64
+}
+
+/// floor(h * n / 2^usize::BITS).
+#[inline(always)]
+pub const fn fastrange_usize(h: usize, n: usize) -> usize {
+    ((h as u128 * n as u128) >> usize::BITS) as usize
+}
+
+/// [fastrange64] for each hash, with the result as bucket index.
+///
+/// Writes min(hashes.len(), buckets.len()) values.
+pub fn fastrange_slice(hashes: &[u64], n: usize, buckets: &mut [usize]) {
+    for (b, &h) in buckets.iter_mut().zip(hashes) {
+        *b = fastrange64(h, n as u64) as usize;
+    }
+}
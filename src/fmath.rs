@@ -0,0 +1,201 @@
+//!
+//! Float functions for no_std.
+//!
+//! core doesn't provide floor(), exp(), ln(), sin() etc. for floats,
+//! these are small replacements. They are accurate to a few ulp,
+//! which is plenty for mapping values.
+//!
+
+const LN_2_HI: f64 = f64::from_bits(0x3fe6_2e42_fee0_0000);
+const LN_2_LO: f64 = f64::from_bits(0x3dea_39ef_3579_3c76);
+const INV_LN_2: f64 = core::f64::consts::LOG2_E;
+
+/// Truncate towards zero.
+#[inline]
+pub(crate) fn trunc(x: f64) -> f64 {
+    // everything above 2^52 is integral anyway.
+    if x.is_nan() || x.abs() >= 4_503_599_627_370_496.0 {
+        return x;
+    }
+    (x as i64) as f64
+}
+
+/// Largest integer <= x.
+#[inline]
+pub(crate) fn floor(x: f64) -> f64 {
+    let t = trunc(x);
+    if t > x {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+/// Smallest integer >= x.
+#[inline]
+pub(crate) fn ceil(x: f64) -> f64 {
+    let t = trunc(x);
+    if t < x {
+        t + 1.0
+    } else {
+        t
+    }
+}
+
+/// Round half away from zero.
+#[inline]
+pub(crate) fn round(x: f64) -> f64 {
+    let t = trunc(x);
+    if (x - t).abs() >= 0.5 {
+        t + x.signum()
+    } else {
+        t
+    }
+}
+
+/// Build 2^k for a k that may be out of the normal range.
+#[inline]
+fn pow2i(k: i32) -> f64 {
+    if k > 1023 {
+        f64::INFINITY
+    } else if k >= -1022 {
+        f64::from_bits(((k + 1023) as u64) << 52)
+    } else if k >= -1074 {
+        f64::from_bits(1u64 << (k + 1074))
+    } else {
+        0.0
+    }
+}
+
+/// e^x
+pub(crate) fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.8 {
+        return f64::INFINITY;
+    }
+    if x < -745.2 {
+        return 0.0;
+    }
+
+    // x = k*ln2 + r, |r| <= ln2/2
+    let k = round(x * INV_LN_2);
+    let r = (x - k * LN_2_HI) - k * LN_2_LO;
+
+    // taylor series, converges well enough for |r| < 0.35
+    let mut p = 1.0;
+    let mut n = 17.0;
+    while n > 0.0 {
+        p = 1.0 + p * r / n;
+        n -= 1.0;
+    }
+
+    // split the scale to stay clear of overflow in the
+    // intermediate and subnormal results.
+    let k = k as i32;
+    let k1 = k / 2;
+    let k2 = k - k1;
+    p * pow2i(k1) * pow2i(k2)
+}
+
+/// Natural logarithm.
+pub(crate) fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+
+    // x = m * 2^e with m in [sqrt(1/2), sqrt(2))
+    let (mut x, mut e) = (x, 0i32);
+    if x < f64::MIN_POSITIVE {
+        // subnormal
+        x *= 18_014_398_509_481_984.0; // 2^54
+        e -= 54;
+    }
+    let bits = x.to_bits();
+    e += ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let mut m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | (1023u64 << 52));
+    if m > core::f64::consts::SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
+
+    // ln(m) = 2 atanh(s)
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let mut p = 0.0;
+    let mut n = 23.0;
+    while n > 1.0 {
+        p = s2 * (1.0 / n + p);
+        n -= 2.0;
+    }
+    let lnm = 2.0 * s + 2.0 * s * p;
+
+    let e = e as f64;
+    e * LN_2_HI + (e * LN_2_LO + lnm)
+}
+
+/// x^y for x >= 0.
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    if y == 0.0 {
+        return 1.0;
+    }
+    if x == 1.0 {
+        return 1.0;
+    }
+    if x == 0.0 {
+        return if y > 0.0 { 0.0 } else { f64::INFINITY };
+    }
+    exp(y * ln(x))
+}
+
+/// Reduce x to [-pi/4, pi/4] and return the quadrant.
+fn rem_pio2(x: f64) -> (f64, i64) {
+    const PIO2_HI: f64 = f64::from_bits(0x3ff9_21fb_5440_0000);
+    const PIO2_LO: f64 = f64::from_bits(0x3dd0_b461_1a62_6331);
+    let k = round(x * core::f64::consts::FRAC_2_PI);
+    let r = (x - k * PIO2_HI) - k * PIO2_LO;
+    (r, k as i64)
+}
+
+fn sin_kernel(r: f64) -> f64 {
+    let r2 = r * r;
+    let mut p = 1.0;
+    let mut n = 19.0;
+    while n > 1.0 {
+        p = 1.0 - p * r2 / (n * (n - 1.0));
+        n -= 2.0;
+    }
+    r * p
+}
+
+fn cos_kernel(r: f64) -> f64 {
+    let r2 = r * r;
+    let mut p = 1.0;
+    let mut n = 18.0;
+    while n > 0.0 {
+        p = 1.0 - p * r2 / (n * (n - 1.0));
+        n -= 2.0;
+    }
+    p
+}
+
+/// Sine. Meant for moderate arguments.
+pub(crate) fn sin(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (r, k) = rem_pio2(x);
+    match k & 3 {
+        0 => sin_kernel(r),
+        1 => cos_kernel(r),
+        2 => -sin_kernel(r),
+        _ => -cos_kernel(r),
+    }
+}
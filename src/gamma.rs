@@ -0,0 +1,326 @@
+//!
+//! Power-law and gamma curves.
+//!
+//! [MapRangeCurve] maps the source range to 0..1, applies a [Curve]
+//! and maps the result onto the target range. The endpoints of the
+//! source range still map exactly to the endpoints of the target range.
+//!
+//! The sRGB and Rec.709 transfer functions are available as
+//! [Curve] and as functions. For u8 <-> u16 linear light there are
+//! table driven variants that need no float math at all.
+//!
+//! ```rust
+//! use map_range_int::gamma::{linear16_to_srgb8, srgb8_to_linear16, Curve, MapRangeCurve};
+//!
+//! let v: u8 = 64u8.map_range_sqrt((0, 255), (0, 255)).expect("in_range");
+//! assert_eq!(v, 127);
+//!
+//! let v: f64 = 128u8
+//!     .map_range_curve((0, 255), (0., 1.), Curve::SrgbToLinear)
+//!     .expect("in_range");
+//! assert!((v - 0.2158605).abs() < 1e-6);
+//!
+//! assert_eq!(srgb8_to_linear16(255), 65535);
+//! assert_eq!(srgb8_to_linear16(128), 14146);
+//! assert_eq!(linear16_to_srgb8(14146), 128);
+//! ```
+//!
+
+use crate::fmath;
+use crate::MapRange;
+
+/// Curves for [MapRangeCurve].
+///
+/// All of them map 0..=1 onto 0..=1 and are exact at both ends.
+/// Pow and Gamma need an exponent that is finite and > 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Curve {
+    /// t^e
+    Pow(f64),
+    /// sqrt(t)
+    Sqrt,
+    /// Gamma encoding t^(1/gamma). Use Pow(gamma) to decode.
+    Gamma(f64),
+    /// sRGB decoding, electro-optical transfer function.
+    SrgbToLinear,
+    /// sRGB encoding.
+    LinearToSrgb,
+    /// Inverse of Rec.709 encoding.
+    Rec709ToLinear,
+    /// Rec.709 encoding, opto-electronic transfer function.
+    LinearToRec709,
+}
+
+impl Curve {
+    /// Apply the curve to t in 0..=1.
+    pub fn apply(self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        if t >= 1.0 {
+            return 1.0;
+        }
+        match self {
+            Curve::Pow(e) => fmath::powf(t, e),
+            Curve::Sqrt => fmath::powf(t, 0.5),
+            Curve::Gamma(g) => fmath::powf(t, 1.0 / g),
+            Curve::SrgbToLinear => srgb_to_linear(t),
+            Curve::LinearToSrgb => linear_to_srgb(t),
+            Curve::Rec709ToLinear => rec709_to_linear(t),
+            Curve::LinearToRec709 => linear_to_rec709(t),
+        }
+    }
+
+    /// Exponent and gamma must be finite and > 0.
+    fn is_valid(self) -> bool {
+        match self {
+            Curve::Pow(e) | Curve::Gamma(e) => e.is_finite() && e > 0.0,
+            _ => true,
+        }
+    }
+}
+
+/// Map ranges to ranges via a curve.
+pub trait MapRangeCurve<Out>
+where
+    Self: PartialOrd + Sized,
+    Out: PartialOrd,
+{
+    /// Map from a source range to a target range and apply
+    /// the curve in between.
+    ///
+    /// Returns None if self is out of bounds for range, if the
+    /// target range is reversed or if the exponent or gamma of the
+    /// curve is not finite and > 0.
+    fn map_range_curve(self, range: (Self, Self), o_range: (Out, Out), curve: Curve)
+        -> Option<Out>;
+
+    /// Power-law mapping t^e.
+    #[inline]
+    fn map_range_pow(self, range: (Self, Self), o_range: (Out, Out), e: f64) -> Option<Out> {
+        self.map_range_curve(range, o_range, Curve::Pow(e))
+    }
+
+    /// Square root mapping.
+    #[inline]
+    fn map_range_sqrt(self, range: (Self, Self), o_range: (Out, Out)) -> Option<Out> {
+        self.map_range_curve(range, o_range, Curve::Sqrt)
+    }
+
+    /// Gamma encoding t^(1/gamma).
+    #[inline]
+    fn map_range_gamma(self, range: (Self, Self), o_range: (Out, Out), gamma: f64) -> Option<Out> {
+        self.map_range_curve(range, o_range, Curve::Gamma(gamma))
+    }
+}
+
+impl<T, Out> MapRangeCurve<Out> for T
+where
+    T: MapRange<f64>,
+    f64: MapRange<Out>,
+    Out: PartialOrd,
+{
+    fn map_range_curve(
+        self,
+        range: (Self, Self),
+        o_range: (Out, Out),
+        curve: Curve,
+    ) -> Option<Out> {
+        if self < range.0 || self > range.1 {
+            return None;
+        }
+        if o_range.1 < o_range.0 || !curve.is_valid() {
+            return None;
+        }
+        if self == range.0 {
+            return Some(o_range.0);
+        }
+        if self == range.1 {
+            return Some(o_range.1);
+        }
+
+        let t: f64 = self.map_range_unchecked(range, (0., 1.));
+        let t = curve.apply(t);
+        Some(t.map_range_unchecked((0., 1.), o_range))
+    }
+}
+
+// -------------------------------------------------------------
+// transfer functions
+// -------------------------------------------------------------
+
+/// sRGB decoding for v in 0..=1.
+pub fn srgb_to_linear(v: f64) -> f64 {
+    if v <= 0.0 {
+        0.0
+    } else if v >= 1.0 {
+        1.0
+    } else if v <= 0.04045 {
+        v / 12.92
+    } else {
+        fmath::powf((v + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// sRGB encoding for l in 0..=1.
+pub fn linear_to_srgb(l: f64) -> f64 {
+    if l <= 0.0 {
+        0.0
+    } else if l >= 1.0 {
+        1.0
+    } else if l <= 0.0031308 {
+        12.92 * l
+    } else {
+        1.055 * fmath::powf(l, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// Rec.709 decoding for v in 0..=1.
+pub fn rec709_to_linear(v: f64) -> f64 {
+    if v <= 0.0 {
+        0.0
+    } else if v >= 1.0 {
+        1.0
+    } else if v < 0.081 {
+        v / 4.5
+    } else {
+        fmath::powf((v + 0.099) / 1.099, 1.0 / 0.45)
+    }
+}
+
+/// Rec.709 encoding for l in 0..=1.
+pub fn linear_to_rec709(l: f64) -> f64 {
+    if l <= 0.0 {
+        0.0
+    } else if l >= 1.0 {
+        1.0
+    } else if l < 0.018 {
+        4.5 * l
+    } else {
+        1.099 * fmath::powf(l, 0.45) - 0.099
+    }
+}
+
+// -------------------------------------------------------------
+// lookup tables
+// -------------------------------------------------------------
+
+/// sRGB u8 to linear u16.
+///
+/// Same as `round(65535 * srgb_to_linear(v / 255))`.
+#[inline]
+pub fn srgb8_to_linear16(v: u8) -> u16 {
+    SRGB_DECODE[v as usize]
+}
+
+/// Linear u16 to sRGB u8.
+///
+/// Same as `round(255 * linear_to_srgb(l / 65535))`.
+/// Inverse of [srgb8_to_linear16].
+#[inline]
+pub fn linear16_to_srgb8(l: u16) -> u8 {
+    (SRGB_ENCODE.partition_point(|v| *v <= l) - 1) as u8
+}
+
+/// Rec.709 u8 to linear u16.
+///
+/// Same as `round(65535 * rec709_to_linear(v / 255))`.
+#[inline]
+pub fn rec709_8_to_linear16(v: u8) -> u16 {
+    REC709_DECODE[v as usize]
+}
+
+/// Linear u16 to Rec.709 u8.
+///
+/// Same as `round(255 * linear_to_rec709(l / 65535))`.
+/// Inverse of [rec709_8_to_linear16].
+#[inline]
+pub fn linear16_to_rec709_8(l: u16) -> u8 {
+    (REC709_ENCODE.partition_point(|v| *v <= l) - 1) as u8
+}
+
+// decoded value for each u8.
+// encode tables contain the smallest u16 that encodes to each u8.
+
+static SRGB_DECODE: [u16; 256] = [
+    0, 20, 40, 60, 80, 99, 119, 139, 159, 179, 199, 219, 241, 264, 288, 313, 340, 367, 396, 427,
+    458, 491, 526, 562, 599, 637, 677, 718, 761, 805, 851, 898, 947, 997, 1048, 1101, 1156, 1212,
+    1270, 1330, 1391, 1453, 1517, 1583, 1651, 1720, 1790, 1863, 1937, 2013, 2090, 2170, 2250, 2333,
+    2418, 2504, 2592, 2681, 2773, 2866, 2961, 3058, 3157, 3258, 3360, 3464, 3570, 3678, 3788, 3900,
+    4014, 4129, 4247, 4366, 4488, 4611, 4736, 4864, 4993, 5124, 5257, 5392, 5530, 5669, 5810, 5953,
+    6099, 6246, 6395, 6547, 6700, 6856, 7014, 7174, 7335, 7500, 7666, 7834, 8004, 8177, 8352, 8528,
+    8708, 8889, 9072, 9258, 9445, 9635, 9828, 10022, 10219, 10417, 10619, 10822, 11028, 11235,
+    11446, 11658, 11873, 12090, 12309, 12530, 12754, 12980, 13209, 13440, 13673, 13909, 14146,
+    14387, 14629, 14874, 15122, 15371, 15623, 15878, 16135, 16394, 16656, 16920, 17187, 17456,
+    17727, 18001, 18277, 18556, 18837, 19121, 19407, 19696, 19987, 20281, 20577, 20876, 21177,
+    21481, 21787, 22096, 22407, 22721, 23038, 23357, 23678, 24002, 24329, 24658, 24990, 25325,
+    25662, 26001, 26344, 26688, 27036, 27386, 27739, 28094, 28452, 28813, 29176, 29542, 29911,
+    30282, 30656, 31033, 31412, 31794, 32179, 32567, 32957, 33350, 33745, 34143, 34544, 34948,
+    35355, 35764, 36176, 36591, 37008, 37429, 37852, 38278, 38706, 39138, 39572, 40009, 40449,
+    40891, 41337, 41785, 42236, 42690, 43147, 43606, 44069, 44534, 45002, 45473, 45947, 46423,
+    46903, 47385, 47871, 48359, 48850, 49344, 49841, 50341, 50844, 51349, 51858, 52369, 52884,
+    53401, 53921, 54445, 54971, 55500, 56032, 56567, 57105, 57646, 58190, 58737, 59287, 59840,
+    60396, 60955, 61517, 62082, 62650, 63221, 63795, 64372, 64952, 65535,
+];
+static SRGB_ENCODE: [u16; 256] = [
+    0, 10, 30, 50, 70, 90, 110, 130, 150, 170, 189, 209, 230, 253, 276, 301, 327, 354, 382, 412,
+    443, 475, 509, 544, 580, 618, 657, 698, 740, 783, 828, 875, 923, 972, 1023, 1075, 1129, 1185,
+    1242, 1300, 1360, 1422, 1486, 1551, 1617, 1685, 1755, 1827, 1900, 1975, 2052, 2130, 2210, 2292,
+    2376, 2461, 2548, 2637, 2727, 2820, 2914, 3010, 3108, 3208, 3309, 3412, 3518, 3625, 3734, 3844,
+    3957, 4072, 4188, 4307, 4427, 4550, 4674, 4800, 4928, 5059, 5191, 5325, 5461, 5599, 5740, 5882,
+    6026, 6173, 6321, 6471, 6624, 6778, 6935, 7094, 7255, 7418, 7583, 7750, 7919, 8091, 8265, 8440,
+    8618, 8798, 8981, 9165, 9352, 9541, 9732, 9925, 10121, 10318, 10518, 10720, 10925, 11132,
+    11341, 11552, 11765, 11981, 12199, 12420, 12643, 12868, 13095, 13325, 13557, 13791, 14028,
+    14267, 14508, 14752, 14998, 15247, 15498, 15751, 16007, 16265, 16525, 16788, 17054, 17321,
+    17592, 17864, 18139, 18417, 18697, 18980, 19264, 19552, 19842, 20134, 20429, 20727, 21027,
+    21329, 21634, 21942, 22252, 22564, 22880, 23197, 23518, 23840, 24166, 24494, 24824, 25158,
+    25493, 25832, 26173, 26516, 26862, 27211, 27563, 27917, 28273, 28633, 28995, 29359, 29727,
+    30097, 30469, 30845, 31223, 31603, 31987, 32373, 32762, 33153, 33547, 33944, 34344, 34747,
+    35152, 35560, 35970, 36384, 36800, 37219, 37640, 38065, 38492, 38922, 39355, 39790, 40229,
+    40670, 41114, 41561, 42011, 42463, 42918, 43377, 43838, 44301, 44768, 45238, 45710, 46185,
+    46663, 47144, 47628, 48115, 48605, 49097, 49593, 50091, 50592, 51096, 51604, 52114, 52627,
+    53142, 53661, 54183, 54708, 55235, 55766, 56300, 56836, 57376, 57918, 58464, 59012, 59564,
+    60118, 60675, 61236, 61799, 62366, 62935, 63508, 64083, 64662, 65244,
+];
+static REC709_DECODE: [u16; 256] = [
+    0, 57, 114, 171, 228, 286, 343, 400, 457, 514, 571, 628, 685, 742, 800, 857, 914, 971, 1028,
+    1085, 1142, 1196, 1254, 1314, 1375, 1438, 1502, 1568, 1636, 1705, 1775, 1847, 1921, 1997, 2074,
+    2152, 2232, 2314, 2398, 2483, 2569, 2658, 2748, 2839, 2933, 3028, 3124, 3222, 3322, 3424, 3527,
+    3632, 3739, 3848, 3958, 4069, 4183, 4298, 4415, 4534, 4655, 4777, 4901, 5026, 5154, 5283, 5414,
+    5547, 5681, 5818, 5956, 6095, 6237, 6381, 6526, 6673, 6822, 6972, 7125, 7279, 7435, 7593, 7753,
+    7914, 8078, 8243, 8410, 8579, 8750, 8922, 9097, 9273, 9451, 9631, 9813, 9997, 10183, 10370,
+    10560, 10751, 10944, 11139, 11337, 11535, 11736, 11939, 12144, 12350, 12559, 12769, 12982,
+    13196, 13412, 13630, 13850, 14072, 14296, 14522, 14750, 14980, 15211, 15445, 15681, 15918,
+    16158, 16400, 16643, 16889, 17136, 17386, 17637, 17891, 18146, 18403, 18663, 18924, 19188,
+    19453, 19721, 19990, 20262, 20535, 20811, 21088, 21368, 21649, 21933, 22219, 22506, 22796,
+    23088, 23381, 23677, 23975, 24275, 24577, 24881, 25187, 25495, 25806, 26118, 26432, 26749,
+    27067, 27388, 27710, 28035, 28362, 28691, 29022, 29355, 29690, 30027, 30366, 30708, 31051,
+    31397, 31745, 32095, 32447, 32801, 33157, 33515, 33876, 34238, 34603, 34970, 35339, 35710,
+    36083, 36458, 36836, 37215, 37597, 37981, 38367, 38755, 39146, 39538, 39933, 40329, 40728,
+    41130, 41533, 41938, 42346, 42756, 43168, 43582, 43998, 44417, 44837, 45260, 45685, 46112,
+    46542, 46973, 47407, 47843, 48281, 48722, 49164, 49609, 50056, 50505, 50957, 51410, 51866,
+    52324, 52784, 53247, 53712, 54178, 54648, 55119, 55593, 56068, 56546, 57027, 57509, 57994,
+    58481, 58970, 59462, 59955, 60451, 60950, 61450, 61953, 62458, 62965, 63474, 63986, 64500,
+    65016, 65535,
+];
+static REC709_ENCODE: [u16; 256] = [
+    0, 29, 86, 143, 200, 257, 315, 372, 429, 486, 543, 600, 657, 714, 771, 829, 886, 943, 1000,
+    1057, 1114, 1171, 1225, 1284, 1345, 1407, 1470, 1535, 1602, 1670, 1740, 1812, 1885, 1959, 2035,
+    2113, 2193, 2274, 2356, 2440, 2526, 2614, 2703, 2794, 2886, 2980, 3076, 3174, 3273, 3374, 3476,
+    3580, 3686, 3794, 3903, 4014, 4127, 4241, 4357, 4475, 4595, 4716, 4839, 4964, 5090, 5219, 5349,
+    5481, 5614, 5750, 5887, 6026, 6167, 6309, 6453, 6600, 6747, 6897, 7049, 7202, 7357, 7514, 7673,
+    7834, 7996, 8160, 8327, 8495, 8664, 8836, 9010, 9185, 9362, 9542, 9723, 9905, 10090, 10277,
+    10465, 10656, 10848, 11042, 11238, 11436, 11636, 11838, 12042, 12247, 12455, 12664, 12876,
+    13089, 13304, 13521, 13740, 13961, 14184, 14409, 14636, 14865, 15096, 15329, 15563, 15800,
+    16038, 16279, 16522, 16766, 17013, 17261, 17512, 17764, 18019, 18275, 18533, 18794, 19056,
+    19321, 19587, 19856, 20126, 20399, 20673, 20950, 21228, 21509, 21791, 22076, 22363, 22651,
+    22942, 23235, 23530, 23826, 24125, 24426, 24729, 25034, 25341, 25651, 25962, 26275, 26591,
+    26908, 27228, 27549, 27873, 28199, 28526, 28856, 29188, 29523, 29859, 30197, 30537, 30880,
+    31225, 31571, 31920, 32271, 32624, 32979, 33336, 33696, 34057, 34421, 34787, 35154, 35524,
+    35897, 36271, 36647, 37026, 37406, 37789, 38174, 38561, 38951, 39342, 39736, 40131, 40529,
+    40929, 41331, 41736, 42142, 42551, 42962, 43375, 43790, 44208, 44627, 45049, 45473, 45899,
+    46327, 46758, 47191, 47625, 48063, 48502, 48943, 49387, 49833, 50281, 50731, 51184, 51638,
+    52095, 52555, 53016, 53479, 53945, 54413, 54884, 55356, 55831, 56308, 56787, 57268, 57752,
+    58238, 58726, 59216, 59709, 60204, 60701, 61200, 61702, 62205, 62712, 63220, 63731, 64243,
+    64759, 65276,
+];
@@ -0,0 +1,361 @@
+//!
+//! Colour gradients.
+//!
+//! [Gradient] is a list of colour stops at positions 0..=65535.
+//! [MapRangeGradient] maps a value onto this position via
+//! [MapRange] and interpolates the colour between the neighbouring
+//! stops. Interpolation is done in sRGB or optionally in linear light.
+//!
+//! There are some built-in colormaps: [VIRIDIS], [MAGMA], [INFERNO],
+//! [TURBO] and [GRAYSCALE]. The first three are sampled from polynomial
+//! fits of the matplotlib colormaps, turbo from Google's polynomial
+//! approximation. They stay within a few 1/255 of the reference
+//! tables, and the endpoints are the reference values.
+//!
+//! ```rust
+//! use map_range_int::gradient::{Gradient, MapRangeGradient, Rgba8, VIRIDIS};
+//!
+//! let c = 0.0f32.map_range_gradient((0., 40.), &VIRIDIS).expect("in_range");
+//! assert_eq!(c.rgb(), [68, 1, 84]);
+//! let c = 40.0f32.map_range_gradient((0., 40.), &VIRIDIS).expect("in_range");
+//! assert_eq!(c.rgb(), [253, 231, 37]);
+//!
+//! let stops = [
+//!     (0, Rgba8::new(0, 0, 255, 255)),
+//!     (65535, Rgba8::new(255, 0, 0, 255)),
+//! ];
+//! let g = Gradient::new(&stops).expect("stops");
+//! let c = 50u8.map_range_gradient((0, 100), &g).expect("in_range");
+//! assert_eq!(c, Rgba8::new(127, 0, 128, 255));
+//!
+//! let g = g.linear_light(true);
+//! let c = 50u8.map_range_gradient((0, 100), &g).expect("in_range");
+//! assert_eq!(c, Rgba8::new(188, 0, 188, 255));
+//! ```
+//!
+
+use crate::gamma::{linear16_to_srgb8, srgb8_to_linear16};
+use crate::MapRange;
+
+/// RGBA colour with 8 bit per channel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgba8 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgba8 {
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Opaque colour.
+    pub const fn rgb8(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    /// RGB part.
+    pub const fn rgb(self) -> [u8; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// As array.
+    pub const fn rgba(self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+impl From<[u8; 4]> for Rgba8 {
+    fn from(v: [u8; 4]) -> Self {
+        Self::new(v[0], v[1], v[2], v[3])
+    }
+}
+
+impl From<[u8; 3]> for Rgba8 {
+    fn from(v: [u8; 3]) -> Self {
+        Self::rgb8(v[0], v[1], v[2])
+    }
+}
+
+/// Multi-stop colour gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gradient<'a> {
+    stops: &'a [(u16, Rgba8)],
+    linear_light: bool,
+}
+
+impl<'a> Gradient<'a> {
+    /// New gradient.
+    ///
+    /// The stop positions must be non-decreasing, two stops at the same
+    /// position give a hard edge. Before the first and after the last
+    /// stop the colour stays constant.
+    ///
+    /// Returns None for an empty list or unordered stops.
+    pub fn new(stops: &'a [(u16, Rgba8)]) -> Option<Self> {
+        if stops.is_empty() {
+            return None;
+        }
+        if !stops.windows(2).all(|w| w[0].0 <= w[1].0) {
+            return None;
+        }
+        Some(Self {
+            stops,
+            linear_light: false,
+        })
+    }
+
+    /// Interpolate in linear light instead of sRGB. Alpha
+    /// is always interpolated linearly.
+    pub fn linear_light(mut self, linear_light: bool) -> Self {
+        self.linear_light = linear_light;
+        self
+    }
+
+    /// Stops.
+    pub fn stops(&self) -> &'a [(u16, Rgba8)] {
+        self.stops
+    }
+
+    /// Colour at position pos.
+    pub fn at(&self, pos: u16) -> Rgba8 {
+        let s = self.stops;
+        // first stop > pos
+        let k = s.partition_point(|v| v.0 <= pos);
+        if k == 0 {
+            return s[0].1;
+        }
+        if k == s.len() || s[k - 1].0 == pos {
+            return s[k - 1].1;
+        }
+
+        let (p0, c0) = s[k - 1];
+        let (p1, c1) = s[k];
+        let d = (pos - p0) as i64;
+        let dp = (p1 - p0) as i64;
+
+        // rounded lerp
+        let lerp = |a: i64, b: i64| {
+            let n = (b - a) * d;
+            if n < 0 {
+                a + (n - dp / 2) / dp
+            } else {
+                a + (n + dp / 2) / dp
+            }
+        };
+        let channel = |a: u8, b: u8| {
+            if self.linear_light {
+                let a = srgb8_to_linear16(a) as i64;
+                let b = srgb8_to_linear16(b) as i64;
+                linear16_to_srgb8(lerp(a, b) as u16)
+            } else {
+                lerp(a as i64, b as i64) as u8
+            }
+        };
+
+        Rgba8 {
+            r: channel(c0.r, c1.r),
+            g: channel(c0.g, c1.g),
+            b: channel(c0.b, c1.b),
+            a: lerp(c0.a as i64, c1.a as i64) as u8,
+        }
+    }
+}
+
+/// Map ranges onto a colour gradient.
+pub trait MapRangeGradient
+where
+    Self: PartialOrd + Sized,
+{
+    /// Map from a source range onto the gradient.
+    ///
+    /// Returns None if self is out of bounds for range.
+    fn map_range_gradient(self, range: (Self, Self), gradient: &Gradient<'_>) -> Option<Rgba8>;
+}
+
+impl<T> MapRangeGradient for T
+where
+    T: MapRange<u16>,
+{
+    #[inline]
+    fn map_range_gradient(self, range: (Self, Self), gradient: &Gradient<'_>) -> Option<Rgba8> {
+        let pos = self.map_range(range, (0, u16::MAX))?;
+        Some(gradient.at(pos))
+    }
+}
+
+/// Black to white.
+pub const GRAYSCALE: Gradient<'static> = Gradient {
+    stops: &[
+        (0, Rgba8::rgb8(0, 0, 0)),
+        (65535, Rgba8::rgb8(255, 255, 255)),
+    ],
+    linear_light: false,
+};
+
+/// matplotlib viridis.
+pub const VIRIDIS: Gradient<'static> = Gradient {
+    stops: &VIRIDIS_STOPS,
+    linear_light: false,
+};
+
+/// matplotlib magma.
+pub const MAGMA: Gradient<'static> = Gradient {
+    stops: &MAGMA_STOPS,
+    linear_light: false,
+};
+
+/// matplotlib inferno.
+pub const INFERNO: Gradient<'static> = Gradient {
+    stops: &INFERNO_STOPS,
+    linear_light: false,
+};
+
+/// Google turbo.
+pub const TURBO: Gradient<'static> = Gradient {
+    stops: &TURBO_STOPS,
+    linear_light: false,
+};
+
+static VIRIDIS_STOPS: [(u16, Rgba8); 33] = [
+    (0, Rgba8::new(68, 1, 84, 255)),
+    (2048, Rgba8::new(72, 13, 96, 255)),
+    (4096, Rgba8::new(72, 24, 106, 255)),
+    (6144, Rgba8::new(72, 34, 115, 255)),
+    (8192, Rgba8::new(71, 45, 123, 255)),
+    (10240, Rgba8::new(69, 55, 129, 255)),
+    (12288, Rgba8::new(67, 64, 134, 255)),
+    (14336, Rgba8::new(64, 74, 137, 255)),
+    (16384, Rgba8::new(61, 82, 140, 255)),
+    (18432, Rgba8::new(57, 91, 141, 255)),
+    (20480, Rgba8::new(52, 99, 142, 255)),
+    (22528, Rgba8::new(48, 107, 142, 255)),
+    (24576, Rgba8::new(43, 114, 142, 255)),
+    (26624, Rgba8::new(39, 122, 142, 255)),
+    (28672, Rgba8::new(35, 129, 141, 255)),
+    (30720, Rgba8::new(33, 137, 140, 255)),
+    (32768, Rgba8::new(31, 144, 139, 255)),
+    (34815, Rgba8::new(31, 152, 138, 255)),
+    (36863, Rgba8::new(33, 159, 135, 255)),
+    (38911, Rgba8::new(36, 166, 132, 255)),
+    (40959, Rgba8::new(42, 174, 128, 255)),
+    (43007, Rgba8::new(50, 181, 123, 255)),
+    (45055, Rgba8::new(61, 188, 116, 255)),
+    (47103, Rgba8::new(74, 194, 107, 255)),
+    (49151, Rgba8::new(90, 200, 97, 255)),
+    (51199, Rgba8::new(108, 206, 86, 255)),
+    (53247, Rgba8::new(128, 211, 73, 255)),
+    (55295, Rgba8::new(149, 216, 60, 255)),
+    (57343, Rgba8::new(172, 220, 48, 255)),
+    (59391, Rgba8::new(194, 223, 37, 255)),
+    (61439, Rgba8::new(216, 226, 29, 255)),
+    (63487, Rgba8::new(236, 229, 27, 255)),
+    (65535, Rgba8::new(253, 231, 37, 255)),
+];
+static MAGMA_STOPS: [(u16, Rgba8); 33] = [
+    (0, Rgba8::new(0, 0, 4, 255)),
+    (2048, Rgba8::new(3, 4, 18, 255)),
+    (4096, Rgba8::new(10, 8, 38, 255)),
+    (6144, Rgba8::new(19, 10, 56, 255)),
+    (8192, Rgba8::new(30, 13, 73, 255)),
+    (10240, Rgba8::new(41, 15, 89, 255)),
+    (12288, Rgba8::new(54, 17, 102, 255)),
+    (14336, Rgba8::new(66, 19, 113, 255)),
+    (16384, Rgba8::new(79, 22, 122, 255)),
+    (18432, Rgba8::new(92, 24, 128, 255)),
+    (20480, Rgba8::new(105, 27, 132, 255)),
+    (22528, Rgba8::new(118, 30, 134, 255)),
+    (24576, Rgba8::new(131, 34, 134, 255)),
+    (26624, Rgba8::new(145, 38, 132, 255)),
+    (28672, Rgba8::new(158, 42, 129, 255)),
+    (30720, Rgba8::new(171, 48, 125, 255)),
+    (32768, Rgba8::new(183, 53, 119, 255)),
+    (34815, Rgba8::new(196, 59, 114, 255)),
+    (36863, Rgba8::new(207, 67, 108, 255)),
+    (38911, Rgba8::new(218, 75, 103, 255)),
+    (40959, Rgba8::new(228, 84, 99, 255)),
+    (43007, Rgba8::new(237, 94, 96, 255)),
+    (45055, Rgba8::new(244, 106, 95, 255)),
+    (47103, Rgba8::new(250, 118, 96, 255)),
+    (49151, Rgba8::new(254, 132, 99, 255)),
+    (51199, Rgba8::new(255, 147, 104, 255)),
+    (53247, Rgba8::new(255, 163, 111, 255)),
+    (55295, Rgba8::new(255, 180, 121, 255)),
+    (57343, Rgba8::new(254, 197, 132, 255)),
+    (59391, Rgba8::new(252, 213, 145, 255)),
+    (61439, Rgba8::new(250, 228, 159, 255)),
+    (63487, Rgba8::new(251, 240, 173, 255)),
+    (65535, Rgba8::new(252, 253, 191, 255)),
+];
+static INFERNO_STOPS: [(u16, Rgba8); 33] = [
+    (0, Rgba8::new(0, 0, 4, 255)),
+    (2048, Rgba8::new(3, 4, 23, 255)),
+    (4096, Rgba8::new(11, 6, 44, 255)),
+    (6144, Rgba8::new(21, 8, 61, 255)),
+    (8192, Rgba8::new(33, 9, 74, 255)),
+    (10240, Rgba8::new(46, 11, 85, 255)),
+    (12288, Rgba8::new(59, 12, 93, 255)),
+    (14336, Rgba8::new(73, 14, 99, 255)),
+    (16384, Rgba8::new(86, 17, 104, 255)),
+    (18432, Rgba8::new(99, 19, 107, 255)),
+    (20480, Rgba8::new(112, 23, 108, 255)),
+    (22528, Rgba8::new(125, 27, 107, 255)),
+    (24576, Rgba8::new(138, 31, 105, 255)),
+    (26624, Rgba8::new(150, 36, 102, 255)),
+    (28672, Rgba8::new(162, 41, 96, 255)),
+    (30720, Rgba8::new(174, 47, 90, 255)),
+    (32768, Rgba8::new(186, 54, 82, 255)),
+    (34815, Rgba8::new(197, 61, 72, 255)),
+    (36863, Rgba8::new(207, 69, 62, 255)),
+    (38911, Rgba8::new(217, 78, 52, 255)),
+    (40959, Rgba8::new(226, 88, 42, 255)),
+    (43007, Rgba8::new(234, 99, 32, 255)),
+    (45055, Rgba8::new(241, 111, 24, 255)),
+    (47103, Rgba8::new(246, 124, 18, 255)),
+    (49151, Rgba8::new(249, 138, 15, 255)),
+    (51199, Rgba8::new(250, 153, 15, 255)),
+    (53247, Rgba8::new(250, 169, 19, 255)),
+    (55295, Rgba8::new(249, 186, 29, 255)),
+    (57343, Rgba8::new(247, 203, 44, 255)),
+    (59391, Rgba8::new(245, 219, 65, 255)),
+    (61439, Rgba8::new(243, 234, 93, 255)),
+    (63487, Rgba8::new(244, 248, 127, 255)),
+    (65535, Rgba8::new(252, 255, 164, 255)),
+];
+static TURBO_STOPS: [(u16, Rgba8); 33] = [
+    (0, Rgba8::new(48, 18, 59, 255)),
+    (2048, Rgba8::new(62, 42, 114, 255)),
+    (4096, Rgba8::new(73, 62, 175, 255)),
+    (6144, Rgba8::new(74, 84, 215, 255)),
+    (8192, Rgba8::new(68, 106, 238, 255)),
+    (10240, Rgba8::new(60, 128, 248, 255)),
+    (12288, Rgba8::new(50, 149, 247, 255)),
+    (14336, Rgba8::new(42, 170, 239, 255)),
+    (16384, Rgba8::new(38, 189, 225, 255)),
+    (18432, Rgba8::new(37, 206, 207, 255)),
+    (20480, Rgba8::new(41, 221, 187, 255)),
+    (22528, Rgba8::new(50, 233, 166, 255)),
+    (24576, Rgba8::new(64, 243, 146, 255)),
+    (26624, Rgba8::new(81, 250, 127, 255)),
+    (28672, Rgba8::new(102, 253, 109, 255)),
+    (30720, Rgba8::new(126, 253, 93, 255)),
+    (32768, Rgba8::new(150, 250, 80, 255)),
+    (34815, Rgba8::new(175, 244, 68, 255)),
+    (36863, Rgba8::new(198, 235, 59, 255)),
+    (38911, Rgba8::new(220, 223, 51, 255)),
+    (40959, Rgba8::new(238, 208, 45, 255)),
+    (43007, Rgba8::new(251, 190, 40, 255)),
+    (45055, Rgba8::new(255, 171, 36, 255)),
+    (47103, Rgba8::new(255, 150, 33, 255)),
+    (49151, Rgba8::new(255, 128, 29, 255)),
+    (51199, Rgba8::new(252, 106, 25, 255)),
+    (53247, Rgba8::new(238, 84, 21, 255)),
+    (55295, Rgba8::new(221, 63, 17, 255)),
+    (57343, Rgba8::new(201, 45, 12, 255)),
+    (59391, Rgba8::new(180, 29, 7, 255)),
+    (61439, Rgba8::new(161, 18, 2, 255)),
+    (63487, Rgba8::new(148, 12, 0, 255)),
+    (65535, Rgba8::new(122, 4, 3, 255)),
+];
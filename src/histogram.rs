@@ -0,0 +1,222 @@
+//!
+//! Histogram with equal-width bins.
+//!
+//! [Histogram] maps each sample onto one of n bins with
+//! `v.map_range(range, (0, n))`. The last bin includes the end of the
+//! range. Samples out of bounds are counted as underflow and
+//! overflow instead of being dropped.
+//!
+//! The bin storage is supplied by the caller.
+//!
+//! ```rust
+//! use map_range_int::histogram::{Bin, Histogram};
+//!
+//! let mut bins = [0u64; 4];
+//! let mut h = Histogram::new((0u8, 100), &mut bins).expect("histogram");
+//! h.extend(&[0, 10, 25, 49, 50, 99, 100, 101, 255]);
+//!
+//! assert_eq!(h.bins(), &[2, 2, 1, 2]);
+//! assert_eq!(h.overflow(), 2);
+//! assert_eq!(h.bin(25), Bin::Bin(1));
+//! assert_eq!(h.bin_range(1), Some((25, 50)));
+//! ```
+//!
+
+use crate::MapRange;
+
+/// Bin for a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bin {
+    /// Below the range.
+    Underflow,
+    /// Bin index.
+    Bin(usize),
+    /// Above the range, or not comparable like NaN.
+    Overflow,
+}
+
+/// Values for a [Histogram].
+pub trait HistogramValue: MapRange<usize> + Copy {
+    /// Smallest value that goes into bin i or above.
+    fn edge(range: (Self, Self), i: usize, n: usize) -> Self;
+}
+
+macro_rules! i_histogram_value {
+    ($ty:ty) => {
+        impl HistogramValue for $ty {
+            fn edge(range: (Self, Self), i: usize, n: usize) -> Self {
+                // bin(v) >= i  <=>  v - lo >= ceil(i * d / n)
+                // can't overflow: d < 2^64, i <= n < 2^64
+                let d = (range.1 as i128 - range.0 as i128) as u128;
+                let off = (i as u128 * d).div_ceil(n as u128);
+                (range.0 as i128 + off as i128) as $ty
+            }
+        }
+    };
+}
+
+i_histogram_value!(u8);
+i_histogram_value!(u16);
+i_histogram_value!(u32);
+i_histogram_value!(u64);
+i_histogram_value!(usize);
+i_histogram_value!(i8);
+i_histogram_value!(i16);
+i_histogram_value!(i32);
+i_histogram_value!(i64);
+i_histogram_value!(isize);
+
+macro_rules! f_histogram_value {
+    ($ty:ty, $bits:ty) => {
+        impl HistogramValue for $ty {
+            fn edge(range: (Self, Self), i: usize, n: usize) -> Self {
+                if i == 0 || range.0 == range.1 {
+                    return range.0;
+                }
+                if i >= n {
+                    return range.1;
+                }
+                // the bits ordered like the values. binary search for
+                // the first one that goes into bin i. can't compute the
+                // edge directly, rounding in map_range may disagree.
+                const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+                let key = |v: $ty| {
+                    let b = v.to_bits();
+                    if b & SIGN != 0 {
+                        !b
+                    } else {
+                        b | SIGN
+                    }
+                };
+                let value = |k: $bits| <$ty>::from_bits(if k & SIGN != 0 { k & !SIGN } else { !k });
+                let (mut lo, mut hi) = (key(range.0), key(range.1));
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    let bin: usize = value(mid).map_range_unchecked(range, (0, n));
+                    if bin >= i {
+                        hi = mid;
+                    } else {
+                        lo = mid + 1;
+                    }
+                }
+                value(lo)
+            }
+        }
+    };
+}
+
+f_histogram_value!(f32, u32);
+f_histogram_value!(f64, u64);
+
+/// Histogram over caller storage.
+#[derive(Debug)]
+pub struct Histogram<'a, T> {
+    range: (T, T),
+    bins: &'a mut [u64],
+    underflow: u64,
+    overflow: u64,
+}
+
+impl<'a, T> Histogram<'a, T>
+where
+    T: HistogramValue,
+{
+    /// New histogram. The bins are cleared, their number
+    /// is the length of the slice.
+    ///
+    /// Returns None if the range is reversed or there are no bins.
+    pub fn new(range: (T, T), bins: &'a mut [u64]) -> Option<Self> {
+        if range.1 < range.0 || bins.is_empty() {
+            return None;
+        }
+        bins.fill(0);
+        Some(Self {
+            range,
+            bins,
+            underflow: 0,
+            overflow: 0,
+        })
+    }
+
+    /// Range.
+    pub fn range(&self) -> (T, T) {
+        self.range
+    }
+
+    /// Bin for a value.
+    pub fn bin(&self, v: T) -> Bin {
+        if v < self.range.0 {
+            Bin::Underflow
+        } else if v <= self.range.1 {
+            let n = self.bins.len();
+            let i: usize = v.map_range_unchecked(self.range, (0, n));
+            Bin::Bin(i.min(n - 1))
+        } else {
+            Bin::Overflow
+        }
+    }
+
+    /// Add a sample.
+    pub fn add(&mut self, v: T) {
+        match self.bin(v) {
+            Bin::Underflow => self.underflow += 1,
+            Bin::Bin(i) => self.bins[i] += 1,
+            Bin::Overflow => self.overflow += 1,
+        }
+    }
+
+    /// Add all samples.
+    pub fn extend(&mut self, samples: &[T]) {
+        for v in samples {
+            self.add(*v);
+        }
+    }
+
+    /// Bin counts.
+    pub fn bins(&self) -> &[u64] {
+        self.bins
+    }
+
+    /// Samples below the range.
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// Samples above the range, or not comparable.
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// Number of samples, including underflow and overflow.
+    pub fn total(&self) -> u64 {
+        self.bins.iter().sum::<u64>() + self.underflow + self.overflow
+    }
+
+    /// Reset all counts.
+    pub fn clear(&mut self) {
+        self.bins.fill(0);
+        self.underflow = 0;
+        self.overflow = 0;
+    }
+
+    /// Edges of bin i, consistent with bin().
+    ///
+    /// The bin contains start..end, the last bin start..=end.
+    /// Bins can be empty for an integer range with fewer values
+    /// than bins.
+    ///
+    /// Returns None if i is out of bounds.
+    pub fn bin_range(&self, i: usize) -> Option<(T, T)> {
+        let n = self.bins.len();
+        if i >= n {
+            return None;
+        }
+        let start = T::edge(self.range, i, n);
+        let end = if i + 1 == n {
+            self.range.1
+        } else {
+            T::edge(self.range, i + 1, n)
+        };
+        Some((start, end))
+    }
+}
@@ -0,0 +1,212 @@
+//!
+//! Component-wise mapping for arrays and tuples.
+//!
+//! [MapRangeLanes] maps each lane through its own pair of ranges,
+//! [MapRangeShared] maps all lanes through the same pair.
+//! All ranges and values are checked before anything is mapped, and
+//! an error tells which lane failed.
+//!
+//! ```rust
+//! use map_range_int::lanes::{LaneError, MapRangeLanes, MapRangeShared};
+//!
+//! // RGB u8 to f32
+//! let rgb: [f32; 3] = [0u8, 51, 255]
+//!     .map_range_shared((0, 255), (0., 1.))
+//!     .expect("in_range");
+//! assert_eq!(rgb, [0., 0.2, 1.]);
+//!
+//! // accelerometer with per axis calibration
+//! let xyz: [i16; 3] = [512u16, 100, 1023]
+//!     .map_range_lanes(
+//!         [(0, 1023), (100, 900), (0, 1023)],
+//!         [(-1000, 1000), (-1000, 1000), (-1000, 1000)],
+//!     )
+//!     .expect("in_range");
+//! assert_eq!(xyz, [0, -1000, 1000]);
+//!
+//! // mixed tuple
+//! let r: Result<(u8, f64), _> =
+//!     (20u16, 0.5f64).map_range_lanes(((0, 10), (0., 1.)), ((0, 100), (0., 10.)));
+//! assert_eq!(r, Err(LaneError::OutOfBounds(0)));
+//! ```
+//!
+
+use crate::MapRange;
+use core::fmt::{Display, Formatter};
+
+/// Error for lane-wise mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LaneError {
+    /// The value of this lane is out of bounds for its range.
+    OutOfBounds(usize),
+    /// The target range of this lane is reversed.
+    ReversedRange(usize),
+    /// The shared target range is reversed.
+    ReversedSharedRange,
+}
+
+impl Display for LaneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LaneError::OutOfBounds(lane) => write!(f, "lane {} is out of bounds", lane),
+            LaneError::ReversedRange(lane) => {
+                write!(f, "lane {} has a reversed target range", lane)
+            }
+            LaneError::ReversedSharedRange => write!(f, "the shared target range is reversed"),
+        }
+    }
+}
+
+/// Map each lane through its own range pair.
+pub trait MapRangeLanes<Out>
+where
+    Self: Sized,
+{
+    /// Source ranges, one per lane.
+    type Ranges;
+    /// Target ranges, one per lane.
+    type ORanges;
+
+    /// Map each lane from its source range to its target range.
+    fn map_range_lanes(
+        self,
+        ranges: Self::Ranges,
+        o_ranges: Self::ORanges,
+    ) -> Result<Out, LaneError>;
+}
+
+/// Map all lanes through the same range pair.
+pub trait MapRangeShared<Out>
+where
+    Self: Sized,
+{
+    /// Source range.
+    type Range;
+    /// Target range.
+    type ORange;
+
+    /// Map all lanes from the source range to the target range.
+    fn map_range_shared(self, range: Self::Range, o_range: Self::ORange) -> Result<Out, LaneError>;
+}
+
+#[inline(always)]
+fn check_lane<T: PartialOrd, O: PartialOrd>(
+    lane: usize,
+    v: &T,
+    range: &(T, T),
+    o_range: &(O, O),
+) -> Result<(), LaneError> {
+    if o_range.1 < o_range.0 {
+        return Err(LaneError::ReversedRange(lane));
+    }
+    if *v < range.0 || *v > range.1 {
+        return Err(LaneError::OutOfBounds(lane));
+    }
+    Ok(())
+}
+
+impl<T, O, const N: usize> MapRangeLanes<[O; N]> for [T; N]
+where
+    T: MapRange<O> + Copy,
+    O: PartialOrd + Copy,
+{
+    type Ranges = [(T, T); N];
+    type ORanges = [(O, O); N];
+
+    fn map_range_lanes(
+        self,
+        ranges: Self::Ranges,
+        o_ranges: Self::ORanges,
+    ) -> Result<[O; N], LaneError> {
+        for i in 0..N {
+            check_lane(i, &self[i], &ranges[i], &o_ranges[i])?;
+        }
+        Ok(core::array::from_fn(|i| {
+            self[i].map_range_unchecked(ranges[i], o_ranges[i])
+        }))
+    }
+}
+
+impl<T, O, const N: usize> MapRangeShared<[O; N]> for [T; N]
+where
+    T: MapRange<O> + Copy,
+    O: PartialOrd + Copy,
+{
+    type Range = (T, T);
+    type ORange = (O, O);
+
+    fn map_range_shared(
+        self,
+        range: Self::Range,
+        o_range: Self::ORange,
+    ) -> Result<[O; N], LaneError> {
+        if o_range.1 < o_range.0 {
+            return Err(LaneError::ReversedSharedRange);
+        }
+        for (i, v) in self.iter().enumerate() {
+            if *v < range.0 || *v > range.1 {
+                return Err(LaneError::OutOfBounds(i));
+            }
+        }
+        Ok(self.map(|v| v.map_range_unchecked(range, o_range)))
+    }
+}
+
+macro_rules! tuple_map_range {
+    ($(($idx:tt, $src:ident, $tgt:ident)),+) => {
+        impl<$($src,)+ $($tgt,)+> MapRangeLanes<($($tgt,)+)> for ($($src,)+)
+        where
+            $($src: MapRange<$tgt> + Copy,)+
+            $($tgt: PartialOrd + Copy,)+
+        {
+            type Ranges = ($(($src, $src),)+);
+            type ORanges = ($(($tgt, $tgt),)+);
+
+            fn map_range_lanes(
+                self,
+                ranges: Self::Ranges,
+                o_ranges: Self::ORanges,
+            ) -> Result<($($tgt,)+), LaneError> {
+                $(check_lane($idx, &self.$idx, &ranges.$idx, &o_ranges.$idx)?;)+
+                Ok(($(self.$idx.map_range_unchecked(ranges.$idx, o_ranges.$idx),)+))
+            }
+        }
+    };
+}
+
+tuple_map_range!((0, A, OA), (1, B, OB));
+tuple_map_range!((0, A, OA), (1, B, OB), (2, C, OC));
+tuple_map_range!((0, A, OA), (1, B, OB), (2, C, OC), (3, D, OD));
+
+macro_rules! tuple_map_range_shared {
+    ($($idx:tt),+; $($src:ident),+; $($tgt:ident),+) => {
+        impl<T, O> MapRangeShared<($($tgt,)+)> for ($($src,)+)
+        where
+            T: MapRange<O> + Copy,
+            O: PartialOrd + Copy,
+        {
+            type Range = (T, T);
+            type ORange = (O, O);
+
+            fn map_range_shared(
+                self,
+                range: Self::Range,
+                o_range: Self::ORange,
+            ) -> Result<($($tgt,)+), LaneError> {
+                if o_range.1 < o_range.0 {
+                    return Err(LaneError::ReversedSharedRange);
+                }
+                $(
+                    if self.$idx < range.0 || self.$idx > range.1 {
+                        return Err(LaneError::OutOfBounds($idx));
+                    }
+                )+
+                Ok(($(self.$idx.map_range_unchecked(range, o_range),)+))
+            }
+        }
+    };
+}
+
+tuple_map_range_shared!(0, 1; T, T; O, O);
+tuple_map_range_shared!(0, 1, 2; T, T, T; O, O, O);
+tuple_map_range_shared!(0, 1, 2, 3; T, T, T, T; O, O, O, O);
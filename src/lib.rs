@@ -1,6 +1,33 @@
 #![no_std]
 #![doc = include_str!("../readme.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod decimate;
+pub mod distribute;
+pub mod easing;
+pub mod fastrange;
+mod fmath;
+pub mod gamma;
+pub mod gradient;
+pub mod histogram;
+pub mod lanes;
+pub mod linspace;
+pub mod log;
+pub mod progress;
+pub mod rate;
+pub mod resample;
+pub mod scale;
+pub mod scrollbar;
+pub mod slider;
+pub mod sparkline;
+pub mod spline;
+pub mod timebase;
+pub mod uniform;
+pub mod viewport;
+pub mod virtual_list;
+
 /// Map ranges to ranges.
 pub trait MapRange<Out>
 where
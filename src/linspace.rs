@@ -0,0 +1,168 @@
+//!
+//! Evenly spaced values over a target range.
+//!
+//! [linspace] yields n values from o_range.0 to o_range.1, value i is
+//! the same as `i.map_range((0, n - 1), o_range)`.
+//!
+//! Integer targets use a DDA with an error accumulator, there is no
+//! division per value. Float targets use map_range() directly.
+//!
+//! ```rust
+//! use map_range_int::linspace::linspace;
+//!
+//! let v: Vec<u8> = linspace((0, 255), 5).expect("range").collect();
+//! assert_eq!(v, vec![0, 63, 127, 191, 255]);
+//!
+//! let v: Vec<i16> = linspace((-100, 100), 4).expect("range").collect();
+//! assert_eq!(v, vec![-100, -34, 33, 100]);
+//!
+//! let v: Vec<f64> = linspace((0., 1.), 5).expect("range").collect();
+//! assert_eq!(v, vec![0., 0.25, 0.5, 0.75, 1.]);
+//! ```
+//!
+
+use crate::MapRange;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+
+/// Values for [linspace].
+pub trait LinspaceValue: Copy + PartialOrd {
+    /// Iterator state.
+    type State: Clone + Debug;
+
+    /// State for n values over o_range.
+    fn start(o_range: (Self, Self), n: usize) -> Self::State;
+
+    /// Value i of n, called with i = 0, 1, 2, ...
+    fn next(o_range: (Self, Self), i: usize, n: usize, state: &mut Self::State) -> Self;
+}
+
+/// DDA state for integer values.
+#[derive(Debug, Clone)]
+pub struct DdaState {
+    den: u128,
+    step: u128,
+    step_err: u128,
+    /// Offset of the next value from o_range.0
+    off: u128,
+    /// Remainder of off, in 1/den.
+    err: u128,
+}
+
+macro_rules! i_linspace_value {
+    ($ty:ty) => {
+        impl LinspaceValue for $ty {
+            type State = DdaState;
+
+            fn start(o_range: (Self, Self), n: usize) -> DdaState {
+                let den = n.saturating_sub(1) as u128;
+                let (step, step_err) = if den > 0 {
+                    let span = (o_range.1 as i128 - o_range.0 as i128) as u128;
+                    (span / den, span % den)
+                } else {
+                    (0, 0)
+                };
+                DdaState {
+                    den,
+                    step,
+                    step_err,
+                    off: 0,
+                    err: 0,
+                }
+            }
+
+            #[inline]
+            fn next(o_range: (Self, Self), _i: usize, _n: usize, state: &mut DdaState) -> Self {
+                let v = (o_range.0 as i128 + state.off as i128) as $ty;
+                state.off += state.step;
+                state.err += state.step_err;
+                if state.err >= state.den {
+                    state.off += 1;
+                    state.err -= state.den;
+                }
+                v
+            }
+        }
+    };
+}
+
+i_linspace_value!(u8);
+i_linspace_value!(u16);
+i_linspace_value!(u32);
+i_linspace_value!(u64);
+i_linspace_value!(usize);
+i_linspace_value!(i8);
+i_linspace_value!(i16);
+i_linspace_value!(i32);
+i_linspace_value!(i64);
+i_linspace_value!(isize);
+
+macro_rules! f_linspace_value {
+    ($ty:ty) => {
+        impl LinspaceValue for $ty {
+            type State = ();
+
+            fn start(_o_range: (Self, Self), _n: usize) {}
+
+            #[inline]
+            fn next(o_range: (Self, Self), i: usize, n: usize, _state: &mut ()) -> Self {
+                i.map_range_unchecked((0, n - 1), o_range)
+            }
+        }
+    };
+}
+
+f_linspace_value!(f32);
+f_linspace_value!(f64);
+
+/// n evenly spaced values over o_range, including both ends.
+///
+/// Returns None if o_range is reversed.
+pub fn linspace<T>(o_range: (T, T), n: usize) -> Option<Linspace<T>>
+where
+    T: LinspaceValue,
+{
+    if o_range.1 < o_range.0 {
+        return None;
+    }
+    Some(Linspace {
+        o_range,
+        n,
+        i: 0,
+        state: T::start(o_range, n),
+    })
+}
+
+/// Iterator for [linspace].
+#[derive(Debug, Clone)]
+pub struct Linspace<T: LinspaceValue> {
+    o_range: (T, T),
+    n: usize,
+    i: usize,
+    state: T::State,
+}
+
+impl<T> Iterator for Linspace<T>
+where
+    T: LinspaceValue,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.n {
+            return None;
+        }
+        let i = self.i;
+        self.i += 1;
+        Some(T::next(self.o_range, i, self.n, &mut self.state))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.n - self.i;
+        (len, Some(len))
+    }
+}
+
+impl<T: LinspaceValue> ExactSizeIterator for Linspace<T> {}
+
+impl<T: LinspaceValue> FusedIterator for Linspace<T> {}
@@ -0,0 +1,455 @@
+//!
+//! Logarithmic and exponential mapping.
+//!
+//! * [MapRangeExp] maps a linear range onto an exponential range.
+//!   Think volume slider or 0..=127 onto 20Hz..=20kHz.
+//! * [MapRangeLog] is the inverse and maps a logarithmic range onto
+//!   a linear one. Think log-scaled chart axis.
+//! * [MapRangeSymExp] and [MapRangeSymLog] do the same for a symmetric
+//!   log scale, which is linear near zero and can cross zero.
+//! * [Decibel] converts between dB and linear gain.
+//!
+//! Float targets are calculated with f64. Integer targets are calculated
+//! with a fixed-point log2/exp2 that doesn't need an FPU.
+//! Both variants map the endpoints of the source range exactly
+//! to the endpoints of the target range.
+//!
+//! ```rust
+//! use map_range_int::log::{MapRangeExp, MapRangeLog};
+//!
+//! let hz: f64 = 0u8.map_range_exp((0, 127), (20., 20000.)).expect("in_range");
+//! assert_eq!(hz, 20.);
+//! let hz: f64 = 127u8.map_range_exp((0, 127), (20., 20000.)).expect("in_range");
+//! assert_eq!(hz, 20000.);
+//!
+//! let hz: u32 = 64u8.map_range_exp((0, 127), (20, 20000)).expect("in_range");
+//! assert_eq!(hz, 650);
+//!
+//! let v: u8 = 650u32.map_range_log((20, 20000), (0, 127)).expect("in_range");
+//! assert_eq!(v, 64);
+//! let v: u8 = 650.0f64.map_range_log((20., 20000.), (0, 127)).expect("in_range");
+//! assert_eq!(v, 64);
+//! ```
+//!
+
+use crate::fmath;
+use crate::MapRange;
+
+/// Map a linear range onto an exponential range.
+pub trait MapRangeExp<Out>
+where
+    Self: PartialOrd + Sized,
+    Out: PartialOrd,
+{
+    /// Map from a linear source range to an exponential target range.
+    ///
+    /// Returns None if self is out of bounds for range, if the
+    /// target range is reversed or if it is not strictly positive.
+    fn map_range_exp(self, range: (Self, Self), o_range: (Out, Out)) -> Option<Out>;
+}
+
+/// Map a logarithmic range onto a linear range.
+///
+/// This is the inverse of [MapRangeExp].
+pub trait MapRangeLog<Out>
+where
+    Self: PartialOrd + Sized,
+    Out: PartialOrd,
+{
+    /// Map from a logarithmic source range to a linear target range.
+    ///
+    /// Returns None if self is out of bounds for range, if the
+    /// source range is not strictly positive or if the target range
+    /// is reversed.
+    fn map_range_log(self, range: (Self, Self), o_range: (Out, Out)) -> Option<Out>;
+}
+
+/// Map a linear range onto a symmetric-log range.
+///
+/// The symlog transform is `sign(x) * ln(1 + |x|/c)`. It is
+/// roughly linear in `-c..c` and logarithmic outside, and can
+/// cross zero.
+pub trait MapRangeSymExp<Out>
+where
+    Self: PartialOrd + Sized,
+    Out: PartialOrd,
+{
+    /// Map from a linear source range to a symlog target range.
+    ///
+    /// Returns None if self is out of bounds for range, if the
+    /// target range is reversed or if c is not positive.
+    fn map_range_symexp(self, range: (Self, Self), o_range: (Out, Out), c: f64) -> Option<Out>;
+}
+
+/// Map a symmetric-log range onto a linear range.
+///
+/// This is the inverse of [MapRangeSymExp].
+pub trait MapRangeSymLog<Out>
+where
+    Self: PartialOrd + Sized,
+    Out: PartialOrd,
+{
+    /// Map from a symlog source range to a linear target range.
+    ///
+    /// Returns None if self is out of bounds for range, if the
+    /// target range is reversed or if c is not positive.
+    fn map_range_symlog(self, range: (Self, Self), o_range: (Out, Out), c: f64) -> Option<Out>;
+}
+
+/// Conversion between decibel and linear gain.
+pub trait Decibel {
+    /// Amplitude dB to linear gain. `10^(db/20)`
+    fn db_to_gain(self) -> Self;
+
+    /// Linear gain to amplitude dB. `20 log10(gain)`
+    ///
+    /// Gives -inf for a gain of 0.
+    fn gain_to_db(self) -> Self;
+
+    /// Power dB to linear ratio. `10^(db/10)`
+    fn db_to_power(self) -> Self;
+
+    /// Linear power ratio to dB. `10 log10(power)`
+    fn power_to_db(self) -> Self;
+}
+
+// -------------------------------------------------------------
+// float
+// -------------------------------------------------------------
+
+#[inline]
+fn symlog(x: f64, c: f64) -> f64 {
+    if x < 0.0 {
+        -fmath::ln(1.0 + -x / c)
+    } else {
+        fmath::ln(1.0 + x / c)
+    }
+}
+
+#[inline]
+fn symexp(y: f64, c: f64) -> f64 {
+    if y < 0.0 {
+        -c * (fmath::exp(-y) - 1.0)
+    } else {
+        c * (fmath::exp(y) - 1.0)
+    }
+}
+
+macro_rules! f_map_range_exp {
+    ($tgt_ty:ty) => {
+        impl<T> MapRangeExp<$tgt_ty> for T
+        where
+            T: MapRange<f64> + Copy,
+        {
+            fn map_range_exp(self, range: (T, T), o_range: ($tgt_ty, $tgt_ty)) -> Option<$tgt_ty> {
+                if self < range.0 || self > range.1 {
+                    return None;
+                }
+                if o_range.0 <= 0.0 || o_range.1 < o_range.0 {
+                    return None;
+                }
+                if self == range.0 {
+                    return Some(o_range.0);
+                }
+                if self == range.1 {
+                    return Some(o_range.1);
+                }
+
+                let t: f64 = self.map_range_unchecked(range, (0., 1.));
+                let l0 = fmath::ln(o_range.0 as f64);
+                let l1 = fmath::ln(o_range.1 as f64);
+                let v = fmath::exp(l0 + t * (l1 - l0)) as $tgt_ty;
+
+                // stay in range despite rounding.
+                Some(v.clamp(o_range.0, o_range.1))
+            }
+        }
+
+        impl<T> MapRangeSymExp<$tgt_ty> for T
+        where
+            T: MapRange<f64> + Copy,
+        {
+            fn map_range_symexp(
+                self,
+                range: (T, T),
+                o_range: ($tgt_ty, $tgt_ty),
+                c: f64,
+            ) -> Option<$tgt_ty> {
+                if self < range.0 || self > range.1 {
+                    return None;
+                }
+                if o_range.1 < o_range.0 || c <= 0.0 {
+                    return None;
+                }
+                if self == range.0 {
+                    return Some(o_range.0);
+                }
+                if self == range.1 {
+                    return Some(o_range.1);
+                }
+
+                let t: f64 = self.map_range_unchecked(range, (0., 1.));
+                let l0 = symlog(o_range.0 as f64, c);
+                let l1 = symlog(o_range.1 as f64, c);
+                let v = symexp(l0 + t * (l1 - l0), c) as $tgt_ty;
+
+                Some(v.clamp(o_range.0, o_range.1))
+            }
+        }
+    };
+}
+
+f_map_range_exp!(f64);
+f_map_range_exp!(f32);
+
+macro_rules! f_map_range_log {
+    ($src_ty:ty) => {
+        impl<Out> MapRangeLog<Out> for $src_ty
+        where
+            f64: MapRange<Out>,
+            Out: PartialOrd,
+        {
+            fn map_range_log(self, range: (Self, Self), o_range: (Out, Out)) -> Option<Out> {
+                if self < range.0 || self > range.1 {
+                    return None;
+                }
+                if range.0 <= 0.0 || o_range.1 < o_range.0 {
+                    return None;
+                }
+                if self == range.0 {
+                    return Some(o_range.0);
+                }
+                if self == range.1 {
+                    return Some(o_range.1);
+                }
+
+                let l = fmath::ln(self as f64);
+                let l0 = fmath::ln(range.0 as f64);
+                let l1 = fmath::ln(range.1 as f64);
+                Some(l.map_range_unchecked((l0, l1), o_range))
+            }
+        }
+
+        impl<Out> MapRangeSymLog<Out> for $src_ty
+        where
+            f64: MapRange<Out>,
+            Out: PartialOrd,
+        {
+            fn map_range_symlog(
+                self,
+                range: (Self, Self),
+                o_range: (Out, Out),
+                c: f64,
+            ) -> Option<Out> {
+                if self < range.0 || self > range.1 {
+                    return None;
+                }
+                if o_range.1 < o_range.0 || c <= 0.0 {
+                    return None;
+                }
+                if self == range.0 {
+                    return Some(o_range.0);
+                }
+                if self == range.1 {
+                    return Some(o_range.1);
+                }
+
+                let l = symlog(self as f64, c);
+                let l0 = symlog(range.0 as f64, c);
+                let l1 = symlog(range.1 as f64, c);
+                Some(l.map_range_unchecked((l0, l1), o_range))
+            }
+        }
+    };
+}
+
+f_map_range_log!(f64);
+f_map_range_log!(f32);
+
+macro_rules! f_decibel {
+    ($ty:ty) => {
+        impl Decibel for $ty {
+            #[inline]
+            fn db_to_gain(self) -> Self {
+                fmath::exp(self as f64 * (core::f64::consts::LN_10 / 20.0)) as $ty
+            }
+
+            #[inline]
+            fn gain_to_db(self) -> Self {
+                (fmath::ln(self as f64) * (20.0 / core::f64::consts::LN_10)) as $ty
+            }
+
+            #[inline]
+            fn db_to_power(self) -> Self {
+                fmath::exp(self as f64 * (core::f64::consts::LN_10 / 10.0)) as $ty
+            }
+
+            #[inline]
+            fn power_to_db(self) -> Self {
+                (fmath::ln(self as f64) * (10.0 / core::f64::consts::LN_10)) as $ty
+            }
+        }
+    };
+}
+
+f_decibel!(f64);
+f_decibel!(f32);
+
+// -------------------------------------------------------------
+// integer
+// -------------------------------------------------------------
+
+/// 2^(2^-k) for k in 1..=32 as Q62.
+const EXP2_FRAC: [u64; 32] = [
+    0x5a827999fcef3242,
+    0x4c1bf828c6dc54b8,
+    0x45cae0f1f545eb73,
+    0x42d561b3e6243d8a,
+    0x4166c34c5615d0ec,
+    0x40b268f9de0183ba,
+    0x4058f6a7ecccd5b6,
+    0x402c6be96af2fb58,
+    0x4016321b687027a8,
+    0x400b18178ba33b14,
+    0x40058bce410147e8,
+    0x4002c5d7bff71daf,
+    0x400162e807ee7e5b,
+    0x4000b1730df6a524,
+    0x400058b9497b8152,
+    0x40002c5c955dd701,
+    0x4000162e46d6f26c,
+    0x40000b1722757b1b,
+    0x4000058b90fd3e0c,
+    0x400002c5c86f3f26,
+    0x40000162e433c79b,
+    0x400000b17218edd0,
+    0x40000058b90c3968,
+    0x4000002c5c860d54,
+    0x400000162e4302d2,
+    0x4000000b17218073,
+    0x400000058b90bffc,
+    0x40000002c5c85fef,
+    0x4000000162e42ff3,
+    0x40000000b17217f9,
+    0x4000000058b90bfc,
+    0x400000002c5c85fe,
+];
+
+/// log2(x) as Q32 fixed point, truncated.
+///
+/// x must not be 0.
+pub(crate) fn log2_q32(x: u64) -> u64 {
+    debug_assert!(x != 0);
+
+    let n = 63 - x.leading_zeros();
+    // normalize to [1,2) as Q62
+    let mut y = if n <= 62 { x << (62 - n) } else { x >> 1 };
+
+    let mut frac = 0u64;
+    for i in 1..=32 {
+        y = ((y as u128 * y as u128) >> 62) as u64;
+        if y >= 1 << 63 {
+            y >>= 1;
+            frac |= 1 << (32 - i);
+        }
+    }
+
+    ((n as u64) << 32) | frac
+}
+
+/// 2^l for a Q32 fixed point l, rounded. Saturates at u64::MAX.
+pub(crate) fn exp2_q32(l: u64) -> u64 {
+    let i = (l >> 32) as u32;
+    let f = l as u32;
+
+    let mut p = 1u64 << 62;
+    for (k, m) in EXP2_FRAC.iter().enumerate() {
+        if f & (1 << (31 - k)) != 0 {
+            p = ((p as u128 * *m as u128) >> 62) as u64;
+        }
+    }
+
+    if i >= 64 {
+        u64::MAX
+    } else if i >= 62 {
+        (p as u128) // p < 2^63
+            .checked_shl(i - 62)
+            .and_then(|v| u64::try_from(v).ok())
+            .unwrap_or(u64::MAX)
+    } else {
+        (p + (1 << (61 - i))) >> (62 - i)
+    }
+}
+
+macro_rules! u_map_range_exp {
+    ($tgt_ty:ty) => {
+        impl<T> MapRangeExp<$tgt_ty> for T
+        where
+            T: MapRange<u64> + Copy,
+        {
+            fn map_range_exp(self, range: (T, T), o_range: ($tgt_ty, $tgt_ty)) -> Option<$tgt_ty> {
+                if self < range.0 || self > range.1 {
+                    return None;
+                }
+                if o_range.0 == 0 || o_range.1 < o_range.0 {
+                    return None;
+                }
+                if self == range.0 {
+                    return Some(o_range.0);
+                }
+                if self == range.1 {
+                    return Some(o_range.1);
+                }
+
+                let l0 = log2_q32(o_range.0 as u64);
+                let l1 = log2_q32(o_range.1 as u64);
+                let d: u64 = self.map_range_unchecked(range, (0, l1 - l0));
+                let v = exp2_q32(l0 + d);
+
+                Some(v.clamp(o_range.0 as u64, o_range.1 as u64) as $tgt_ty)
+            }
+        }
+    };
+}
+
+u_map_range_exp!(u8);
+u_map_range_exp!(u16);
+u_map_range_exp!(u32);
+u_map_range_exp!(u64);
+u_map_range_exp!(usize);
+
+macro_rules! u_map_range_log {
+    ($src_ty:ty) => {
+        impl<Out> MapRangeLog<Out> for $src_ty
+        where
+            u64: MapRange<Out>,
+            Out: PartialOrd,
+        {
+            fn map_range_log(self, range: (Self, Self), o_range: (Out, Out)) -> Option<Out> {
+                if self < range.0 || self > range.1 {
+                    return None;
+                }
+                if range.0 == 0 || o_range.1 < o_range.0 {
+                    return None;
+                }
+                if self == range.0 {
+                    return Some(o_range.0);
+                }
+                if self == range.1 {
+                    return Some(o_range.1);
+                }
+
+                let l = log2_q32(self as u64);
+                let l0 = log2_q32(range.0 as u64);
+                let l1 = log2_q32(range.1 as u64);
+                Some((l - l0).map_range_unchecked((0, l1 - l0), o_range))
+            }
+        }
+    };
+}
+
+u_map_range_log!(u8);
+u_map_range_log!(u16);
+u_map_range_log!(u32);
+u_map_range_log!(u64);
+u_map_range_log!(usize);
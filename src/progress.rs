@@ -0,0 +1,109 @@
+//!
+//! Progress bars and gauges with eighth-block resolution.
+//!
+//! [MapRangeProgress] maps a value onto `0..=cells*8` and splits the
+//! result in full cells, one partial cell and the empty rest.
+//! The start of the range is always empty, the end of the range always
+//! completely full.
+//!
+//! ```rust
+//! use map_range_int::progress::{MapRangeProgress, Progress, HBLOCKS};
+//!
+//! let p = 37u8.map_range_progress((0, 100), 10).expect("in_range");
+//! assert_eq!(p, Progress { full: 3, partial: 5, empty: 6 });
+//!
+//! let bar: String = p.glyphs().map(|v| HBLOCKS[v as usize]).collect();
+//! assert_eq!(bar, "███▋      ");
+//!
+//! let p = 1.0f64.map_range_progress((0., 1.), 10).expect("in_range");
+//! assert_eq!(p.full, 10);
+//! ```
+//!
+
+use crate::MapRange;
+
+/// Horizontal eighth blocks, growing left to right. Index with the
+/// glyph index from [Progress].
+pub const HBLOCKS: [char; 9] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// Vertical eighth blocks, growing bottom to top.
+pub const VBLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Progress split into cells.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Progress {
+    /// Number of full cells.
+    pub full: usize,
+    /// Eighths of the partial cell, 0..8. If this is 0 there is
+    /// no partial cell.
+    pub partial: u8,
+    /// Number of empty cells after the partial cell.
+    pub empty: usize,
+}
+
+impl Progress {
+    /// Split eighths into cells.
+    pub fn from_eighths(eighths: usize, cells: usize) -> Self {
+        let eighths = eighths.min(cells.saturating_mul(8));
+        let full = eighths / 8;
+        let partial = (eighths % 8) as u8;
+        let empty = cells - full - if partial > 0 { 1 } else { 0 };
+        Self {
+            full,
+            partial,
+            empty,
+        }
+    }
+
+    /// Total in eighths.
+    pub fn eighths(&self) -> usize {
+        self.full * 8 + self.partial as usize
+    }
+
+    /// Glyph index 0..=8 for each cell, for use with [HBLOCKS] or
+    /// [VBLOCKS].
+    // repeat_n() needs rust 1.82.
+    #[allow(clippy::manual_repeat_n)]
+    pub fn glyphs(&self) -> impl Iterator<Item = u8> {
+        let partial = if self.partial > 0 {
+            Some(self.partial)
+        } else {
+            None
+        };
+        core::iter::repeat(8)
+            .take(self.full)
+            .chain(partial)
+            .chain(core::iter::repeat(0).take(self.empty))
+    }
+}
+
+/// Map ranges onto a progress bar.
+pub trait MapRangeProgress
+where
+    Self: PartialOrd + Sized,
+{
+    /// Map from a source range onto `cells` with eighth-block
+    /// resolution.
+    ///
+    /// Returns None if self is out of bounds for range.
+    fn map_range_progress(self, range: (Self, Self), cells: usize) -> Option<Progress>;
+}
+
+impl<T> MapRangeProgress for T
+where
+    T: MapRange<usize>,
+{
+    fn map_range_progress(self, range: (Self, Self), cells: usize) -> Option<Progress> {
+        if self < range.0 || self > range.1 {
+            return None;
+        }
+        let total = cells.saturating_mul(8);
+        let eighths = if self == range.1 {
+            // float math must not make this 99%.
+            total
+        } else {
+            self.map_range_unchecked(range, (0, total))
+        };
+        Some(Progress::from_eighths(eighths, cells))
+    }
+}
@@ -0,0 +1,111 @@
+//!
+//! Drift-free conversion of a running tick count between clocks.
+//!
+//! [RateConverter] carries the remainder from one call to the next.
+//! After any sequence of calls the sum of the results is exactly
+//! `floor(sum of ticks * to / from)`, as if all ticks were converted
+//! at once.
+//!
+//! ```rust
+//! use map_range_int::rate::RateConverter;
+//!
+//! // 32768 Hz RTC to ms
+//! let mut rtc = RateConverter::new(32768, 1000).expect("rate");
+//! let mut ms = 0;
+//! for _ in 0..32768 {
+//!     ms += rtc.convert(1).expect("fits");
+//! }
+//! assert_eq!(ms, 1000);
+//!
+//! // 48 kHz to 44.1 kHz in blocks of 480 samples
+//! let mut sr = RateConverter::new(48000, 44100).expect("rate");
+//! assert_eq!(sr.convert(480), Some(441));
+//!
+//! // encoder counts to µm, 2000 counts per 5 mm
+//! let mut enc = RateConverter::new(2000, 5000).expect("rate");
+//! assert_eq!(enc.convert_signed(3), Some(7));
+//! assert_eq!(enc.convert_signed(-3), Some(-7));
+//!
+//! // too large
+//! let mut us = RateConverter::new(1, 1000).expect("rate");
+//! assert_eq!(us.convert(u64::MAX), None);
+//! ```
+//!
+
+/// Stateful tick converter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RateConverter {
+    from: u64,
+    to: u64,
+    /// Carried remainder, in 1/from of an output tick.
+    rem: u64,
+}
+
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+impl RateConverter {
+    /// New converter from rate `from` to rate `to`.
+    ///
+    /// Returns None if from is 0.
+    pub const fn new(from: u64, to: u64) -> Option<Self> {
+        if from == 0 {
+            return None;
+        }
+        let g = gcd(from, to);
+        Some(Self {
+            from: from / g,
+            to: to / g,
+            rem: 0,
+        })
+    }
+
+    /// Rates reduced by their gcd.
+    pub fn rates(&self) -> (u64, u64) {
+        (self.from, self.to)
+    }
+
+    /// Carried remainder, in 1/from of an output tick.
+    pub fn remainder(&self) -> u64 {
+        self.rem
+    }
+
+    /// Drop the carried remainder.
+    pub fn reset(&mut self) {
+        self.rem = 0;
+    }
+
+    /// Convert ticks and carry the remainder.
+    ///
+    /// Returns None if the result doesn't fit u64. The remainder
+    /// is unchanged then.
+    pub fn convert(&mut self, ticks: u64) -> Option<u64> {
+        // can't overflow: (2^64-1)^2 + 2^64-1 < 2^128
+        let v = ticks as u128 * self.to as u128 + self.rem as u128;
+        let from = self.from as u128;
+        let r = u64::try_from(v / from).ok()?;
+        self.rem = (v % from) as u64;
+        Some(r)
+    }
+
+    /// Convert ticks that may go backwards and carry the remainder.
+    /// Rounds towards negative infinity, so the sum of the results
+    /// stays exact in both directions.
+    ///
+    /// Returns None if the result doesn't fit i64. The remainder
+    /// is unchanged then.
+    pub fn convert_signed(&mut self, ticks: i64) -> Option<i64> {
+        // can't overflow: |2^63 * (2^64-1)| + 2^64 < 2^127
+        let v = ticks as i128 * self.to as i128 + self.rem as i128;
+        let from = self.from as i128;
+        let r = i64::try_from(v.div_euclid(from)).ok()?;
+        self.rem = v.rem_euclid(from) as u64;
+        Some(r)
+    }
+}
@@ -0,0 +1,254 @@
+//!
+//! Resampling index maps.
+//!
+//! Maps destination indices to source indices, with the integer
+//! [MapRange] so there is no float drift. [Align] chooses how the
+//! source and destination grids line up.
+//!
+//! [resample_linear] interpolates between the two neighbouring
+//! samples. The fractional source position is exact, it's kept as
+//! a fraction of integers.
+//!
+//! ```rust
+//! use map_range_int::resample::{
+//!     nearest_indices, resample_linear, resample_linear_map, resample_nearest, Align,
+//! };
+//!
+//! // 4 -> 6
+//! let idx: Vec<usize> = nearest_indices(4, 6, Align::Asymmetric).collect();
+//! assert_eq!(idx, vec![0, 0, 1, 2, 2, 3]);
+//! let idx: Vec<usize> = nearest_indices(4, 6, Align::HalfPixel).collect();
+//! assert_eq!(idx, vec![0, 1, 1, 2, 3, 3]);
+//! let idx: Vec<usize> = nearest_indices(4, 6, Align::Corners).collect();
+//! assert_eq!(idx, vec![0, 1, 1, 2, 2, 3]);
+//!
+//! let mut dst = [0u8; 3];
+//! resample_nearest(&[10, 20, 30, 40, 50, 60], &mut dst, Align::HalfPixel);
+//! assert_eq!(dst, [20, 40, 60]);
+//!
+//! let mut dst = [0u8; 5];
+//! resample_linear(&[0u8, 100, 50], &mut dst);
+//! assert_eq!(dst, [0, 50, 100, 75, 50]);
+//!
+//! // u8 samples to f32 in 0..1
+//! let mut dst = [0f32; 3];
+//! resample_linear_map(&[0u8, 255], &mut dst, (0, 255), (0., 1.));
+//! assert_eq!(dst, [0., 0.5019608, 1.]);
+//! ```
+//!
+
+use crate::MapRange;
+use core::iter::FusedIterator;
+
+/// How source and destination line up.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// `floor(d * src_len / dst_len)`. The first samples line up,
+    /// the rest is shifted towards the start. PyTorch `nearest`,
+    /// OpenCV `INTER_NEAREST`.
+    Asymmetric,
+    /// `floor((d + 0.5) * src_len / dst_len)`. Pixel centres line
+    /// up. PyTorch `nearest-exact`, the half-pixel offset in graphics.
+    #[default]
+    HalfPixel,
+    /// `round(d * (src_len - 1) / (dst_len - 1))`. The first and the
+    /// last samples line up. `align_corners=True`.
+    Corners,
+}
+
+/// Source index for the destination index d.
+///
+/// Returns None if d >= dst_len or src_len is 0.
+pub fn nearest_index(d: usize, src_len: usize, dst_len: usize, align: Align) -> Option<usize> {
+    if d >= dst_len || src_len == 0 {
+        return None;
+    }
+    Some(nearest_unchecked(d, src_len, dst_len, align))
+}
+
+#[inline]
+fn nearest_unchecked(d: usize, src_len: usize, dst_len: usize, align: Align) -> usize {
+    match align {
+        Align::Asymmetric => d.map_range_unchecked((0, dst_len), (0, src_len)),
+        Align::HalfPixel => (2 * d + 1).map_range_unchecked((0, 2 * dst_len), (0, src_len)),
+        Align::Corners => {
+            // round(x) == floor((floor(2x) + 1) / 2)
+            let x2: usize = d.map_range_unchecked((0, dst_len - 1), (0, 2 * (src_len - 1)));
+            x2.div_ceil(2)
+        }
+    }
+}
+
+/// Source indices for a whole destination row.
+///
+/// Yields nothing if src_len is 0.
+pub fn nearest_indices(src_len: usize, dst_len: usize, align: Align) -> NearestIndices {
+    NearestIndices {
+        src_len,
+        dst_len: if src_len == 0 { 0 } else { dst_len },
+        align,
+        d: 0,
+    }
+}
+
+/// Iterator for [nearest_indices].
+#[derive(Debug, Clone)]
+pub struct NearestIndices {
+    src_len: usize,
+    dst_len: usize,
+    align: Align,
+    d: usize,
+}
+
+impl Iterator for NearestIndices {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.d >= self.dst_len {
+            return None;
+        }
+        let d = self.d;
+        self.d += 1;
+        Some(nearest_unchecked(d, self.src_len, self.dst_len, self.align))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.dst_len - self.d;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for NearestIndices {}
+
+impl FusedIterator for NearestIndices {}
+
+/// Nearest-neighbour resampling of src into dst.
+///
+/// Leaves dst unchanged if src is empty.
+pub fn resample_nearest<T: Copy>(src: &[T], dst: &mut [T], align: Align) {
+    let idx = nearest_indices(src.len(), dst.len(), align);
+    for (v, s) in dst.iter_mut().zip(idx) {
+        *v = src[s];
+    }
+}
+
+/// Samples for [resample_linear].
+pub trait Lerp: Copy {
+    /// a + (b - a) * num / den, with num < den.
+    /// Integers round to nearest.
+    fn lerp(a: Self, b: Self, num: u128, den: u128) -> Self;
+}
+
+macro_rules! i_lerp {
+    ($ty:ty) => {
+        impl Lerp for $ty {
+            #[inline]
+            fn lerp(a: Self, b: Self, num: u128, den: u128) -> Self {
+                // can't overflow: diff < 2^64, num < den < 2^64
+                let diff = (a as i128).abs_diff(b as i128);
+                let off = ((diff * num + den / 2) / den) as i128;
+                if b >= a {
+                    (a as i128 + off) as $ty
+                } else {
+                    (a as i128 - off) as $ty
+                }
+            }
+        }
+    };
+}
+
+i_lerp!(u8);
+i_lerp!(u16);
+i_lerp!(u32);
+i_lerp!(u64);
+i_lerp!(usize);
+i_lerp!(i8);
+i_lerp!(i16);
+i_lerp!(i32);
+i_lerp!(i64);
+i_lerp!(isize);
+
+macro_rules! f_lerp {
+    ($ty:ty) => {
+        impl Lerp for $ty {
+            #[inline]
+            fn lerp(a: Self, b: Self, num: u128, den: u128) -> Self {
+                let t = num as f64 / den as f64;
+                (a as f64 + (b as f64 - a as f64) * t) as $ty
+            }
+        }
+    };
+}
+
+f_lerp!(f32);
+f_lerp!(f64);
+
+/// Linear interpolation of src at dst index d, for dst_len > 1.
+#[inline]
+fn lerp_at<T: Lerp>(src: &[T], d: usize, dst_len: usize) -> T {
+    let last = src.len() - 1;
+    let den = dst_len - 1;
+    let idx: usize = d.map_range_unchecked((0, den), (0, last));
+    let rem = d as u128 * last as u128 - idx as u128 * den as u128;
+    if rem == 0 {
+        src[idx]
+    } else {
+        T::lerp(src[idx], src[idx + 1], rem, den as u128)
+    }
+}
+
+/// Linear resampling of src into dst.
+///
+/// The first and the last samples line up, like [Align::Corners].
+/// Leaves dst unchanged if src is empty.
+pub fn resample_linear<T, U>(src: &[T], dst: &mut [U])
+where
+    T: Lerp,
+    U: From<T>,
+{
+    if src.is_empty() {
+        return;
+    }
+    let dst_len = dst.len();
+    if dst_len == 1 {
+        dst[0] = U::from(src[0]);
+        return;
+    }
+    for (d, v) in dst.iter_mut().enumerate() {
+        *v = U::from(lerp_at(src, d, dst_len));
+    }
+}
+
+/// Linear resampling of src into dst, and map each value from range
+/// to o_range. Values out of bounds are clamped to the range.
+///
+/// The first and the last samples line up, like [Align::Corners].
+/// Leaves dst unchanged if src is empty, range is reversed or
+/// o_range is reversed.
+pub fn resample_linear_map<T, U>(src: &[T], dst: &mut [U], range: (T, T), o_range: (U, U))
+where
+    T: Lerp + MapRange<U>,
+    U: PartialOrd + Copy,
+{
+    if src.is_empty() || range.1 < range.0 || o_range.1 < o_range.0 {
+        return;
+    }
+    let map = |v: T| {
+        let v = if v < range.0 {
+            range.0
+        } else if v > range.1 {
+            range.1
+        } else {
+            v
+        };
+        v.map_range_unchecked(range, o_range)
+    };
+    let dst_len = dst.len();
+    if dst_len == 1 {
+        dst[0] = map(src[0]);
+        return;
+    }
+    for (d, v) in dst.iter_mut().enumerate() {
+        *v = map(lerp_at(src, d, dst_len));
+    }
+}
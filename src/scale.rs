@@ -0,0 +1,571 @@
+//!
+//! Scales for chart axes.
+//!
+//! [LinearScale] maps a continuous f64 domain onto a pixel range
+//! like d3's `scaleLinear`. It can extend the domain to round
+//! numbers and generates ticks at 1, 2 or 5 × 10^k with their pixel
+//! positions.
+//!
+//! [BandScale] and [PointScale] map category indices onto pixels
+//! like d3's `scaleBand` and `scalePoint`. They work with integer
+//! pixels, bands differ by at most 1 pixel and the range is filled
+//! exactly.
+//!
+//! The pixel range may be reversed for a y-axis that grows upwards.
+//! Both ends of the domain always map exactly to the ends of the
+//! pixel range.
+//!
+//! ```rust
+//! use map_range_int::scale::{BandScale, LinearScale, PointScale};
+//!
+//! // y axis with 0 at the bottom row.
+//! let scale = LinearScale::new((0.3, 9.7), (20u16, 0))
+//!     .expect("scale")
+//!     .nice(5);
+//! assert_eq!(scale.domain(), (0., 10.));
+//! assert_eq!(scale.scale(0.), Some(20));
+//! assert_eq!(scale.scale(10.), Some(0));
+//!
+//! let ticks: Vec<(f64, u16)> = scale.ticks(5).collect();
+//! assert_eq!(
+//!     ticks,
+//!     vec![(0., 20), (2., 16), (4., 12), (6., 8), (8., 4), (10., 0)]
+//! );
+//! assert_eq!(scale.precision(5), 0);
+//!
+//! // mouse hover
+//! assert_eq!(scale.invert(15), Some(2.5));
+//!
+//! // 4 bars in 30 cells with 1/4 padding between them.
+//! let bars = BandScale::new(4, (0u16, 30)).expect("scale").padding_inner(0.25);
+//! assert_eq!(bars.band(0), Some((0, 6)));
+//! assert_eq!(bars.band(1), Some((8, 6)));
+//! assert_eq!(bars.band(3), Some((24, 6)));
+//! assert_eq!(bars.index_at(9), Some(1));
+//! assert_eq!(bars.index_at(7), None);
+//!
+//! let points = PointScale::new(3, (0u16, 30)).expect("scale");
+//! assert_eq!(points.point(1), Some(15));
+//! ```
+//!
+
+use crate::distribute::DistributeValue;
+use crate::fmath;
+use crate::MapRange;
+use core::iter::FusedIterator;
+
+/// 10^p, exact for 0 <= p <= 22.
+fn pow10(p: i32) -> f64 {
+    if (0..=22).contains(&p) {
+        let mut v = 1.0;
+        for _ in 0..p {
+            v *= 10.0;
+        }
+        v
+    } else if (-22..0).contains(&p) {
+        1.0 / pow10(-p)
+    } else {
+        fmath::powf(10.0, p as f64)
+    }
+}
+
+/// floor(log10(v)) for v > 0.
+fn log10_floor(v: f64) -> i32 {
+    let mut e = fmath::floor(fmath::ln(v) / core::f64::consts::LN_10) as i32;
+    while pow10(e) > v {
+        e -= 1;
+    }
+    while pow10(e + 1) <= v {
+        e += 1;
+    }
+    e
+}
+
+/// Tick indices and increment, d3's tickSpec.
+///
+/// A negative increment means the ticks are i / -inc, this
+/// avoids 0.1 * 3 == 0.30000000000000004.
+fn tick_spec(start: f64, stop: f64, count: f64) -> (i64, i64, f64) {
+    let step = (stop - start) / count;
+    if !step.is_finite() || step <= 0.0 {
+        return (0, -1, 1.0);
+    }
+    let power = log10_floor(step);
+    let error = step / pow10(power);
+    let factor = if error >= 7.0710678118654755 {
+        10.0
+    } else if error >= 3.1622776601683795 {
+        5.0
+    } else if error >= core::f64::consts::SQRT_2 {
+        2.0
+    } else {
+        1.0
+    };
+
+    let (mut i1, mut i2, inc);
+    if power < 0 {
+        let d = pow10(-power) / factor;
+        i1 = fmath::round(start * d);
+        i2 = fmath::round(stop * d);
+        if i1 / d < start {
+            i1 += 1.0;
+        }
+        if i2 / d > stop {
+            i2 -= 1.0;
+        }
+        inc = -d;
+    } else {
+        let m = pow10(power) * factor;
+        i1 = fmath::round(start / m);
+        i2 = fmath::round(stop / m);
+        if i1 * m < start {
+            i1 += 1.0;
+        }
+        if i2 * m > stop {
+            i2 -= 1.0;
+        }
+        inc = m;
+    }
+    if i2 < i1 && (0.5..2.0).contains(&count) {
+        return tick_spec(start, stop, count * 2.0);
+    }
+    (i1 as i64, i2 as i64, inc)
+}
+
+/// Linear scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearScale<P> {
+    domain: (f64, f64),
+    range: (P, P),
+}
+
+impl<P> LinearScale<P>
+where
+    P: Copy + PartialOrd + MapRange<f64>,
+    f64: MapRange<P>,
+{
+    /// New scale.
+    ///
+    /// Both domain and range may be reversed. Returns None if
+    /// the domain is empty or not finite.
+    pub fn new(domain: (f64, f64), range: (P, P)) -> Option<Self> {
+        if domain.0 == domain.1 || !(domain.1 - domain.0).is_finite() {
+            return None;
+        }
+        Some(Self { domain, range })
+    }
+
+    /// Domain.
+    pub fn domain(&self) -> (f64, f64) {
+        self.domain
+    }
+
+    /// Pixel range.
+    pub fn range(&self) -> (P, P) {
+        self.range
+    }
+
+    /// Domain ascending, range ascending, and whether one of them
+    /// is reversed.
+    #[inline]
+    fn sorted(&self) -> ((f64, f64), (P, P), bool) {
+        let (d, d_rev) = if self.domain.0 <= self.domain.1 {
+            (self.domain, false)
+        } else {
+            ((self.domain.1, self.domain.0), true)
+        };
+        let (r, r_rev) = if self.range.0 <= self.range.1 {
+            (self.range, false)
+        } else {
+            ((self.range.1, self.range.0), true)
+        };
+        (d, r, d_rev != r_rev)
+    }
+
+    /// Map a domain value to pixels.
+    ///
+    /// Returns None if v is outside the domain.
+    pub fn scale(&self, v: f64) -> Option<P> {
+        let (d, r, flip) = self.sorted();
+        // float math doesn't always hit the far end exactly.
+        if v == d.0 {
+            return Some(if flip { r.1 } else { r.0 });
+        } else if v == d.1 {
+            return Some(if flip { r.0 } else { r.1 });
+        }
+        if flip {
+            // negation is exact.
+            (-v).map_range((-d.1, -d.0), r)
+        } else {
+            v.map_range(d, r)
+        }
+    }
+
+    /// Map a domain value to pixels. Values outside the domain
+    /// are clamped.
+    pub fn scale_clamped(&self, v: f64) -> P {
+        let (d, ..) = self.sorted();
+        let v = if v < d.0 {
+            d.0
+        } else if v > d.1 {
+            d.1
+        } else {
+            v
+        };
+        self.scale(v).unwrap_or(self.range.0)
+    }
+
+    /// Map pixels back to the domain.
+    ///
+    /// Returns None if p is outside the pixel range.
+    pub fn invert(&self, p: P) -> Option<f64> {
+        let (d, r, flip) = self.sorted();
+        if p == r.0 {
+            return Some(if flip { d.1 } else { d.0 });
+        } else if p == r.1 {
+            return Some(if flip { d.0 } else { d.1 });
+        }
+        if flip {
+            p.map_range(r, (-d.1, -d.0)).map(|v| -v)
+        } else {
+            p.map_range(r, d)
+        }
+    }
+
+    /// Extend the domain to round numbers, for about count ticks.
+    pub fn nice(mut self, count: usize) -> Self {
+        let (mut d, _, _) = self.sorted();
+        let mut prev = 0.0;
+        for _ in 0..10 {
+            let step = self.tick_step_for(d, count);
+            if step == prev || step == 0.0 {
+                break;
+            }
+            if step > 0.0 {
+                d = (
+                    fmath::floor(d.0 / step) * step,
+                    fmath::ceil(d.1 / step) * step,
+                );
+            } else {
+                d = (
+                    fmath::ceil(d.0 * step) / step,
+                    fmath::floor(d.1 * step) / step,
+                );
+            }
+            prev = step;
+        }
+        self.domain = if self.domain.0 <= self.domain.1 {
+            d
+        } else {
+            (d.1, d.0)
+        };
+        self
+    }
+
+    /// d3's tickIncrement. Negative values are the inverse.
+    fn tick_step_for(&self, d: (f64, f64), count: usize) -> f64 {
+        let (_, _, inc) = tick_spec(d.0, d.1, count as f64);
+        inc
+    }
+
+    /// Distance between ticks, for about count ticks.
+    pub fn tick_step(&self, count: usize) -> f64 {
+        let (d, ..) = self.sorted();
+        let inc = self.tick_step_for(d, count);
+        if inc < 0.0 {
+            -1.0 / inc
+        } else {
+            inc
+        }
+    }
+
+    /// Number of decimals needed for tick labels.
+    pub fn precision(&self, count: usize) -> usize {
+        let step = self.tick_step(count);
+        let p = -log10_floor(step);
+        if p > 0 {
+            p as usize
+        } else {
+            0
+        }
+    }
+
+    /// About count ticks at 1, 2 or 5 × 10^k inside the domain,
+    /// ascending. Yields (value, pixel position).
+    pub fn ticks(&self, count: usize) -> Ticks<P> {
+        let (d, ..) = self.sorted();
+        let (i1, i2, inc) = if count == 0 {
+            (0, -1, 1.0)
+        } else {
+            tick_spec(d.0, d.1, count as f64)
+        };
+        Ticks {
+            scale: *self,
+            i: i1,
+            end: i2,
+            inc,
+        }
+    }
+}
+
+/// Iterator for [LinearScale::ticks].
+#[derive(Debug, Clone)]
+pub struct Ticks<P> {
+    scale: LinearScale<P>,
+    i: i64,
+    end: i64,
+    inc: f64,
+}
+
+impl<P> Iterator for Ticks<P>
+where
+    P: Copy + PartialOrd + MapRange<f64>,
+    f64: MapRange<P>,
+{
+    type Item = (f64, P);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.i <= self.end {
+            let i = self.i as f64;
+            self.i += 1;
+            let v = if self.inc < 0.0 {
+                i / -self.inc
+            } else {
+                i * self.inc
+            };
+            if let Some(p) = self.scale.scale(v) {
+                return Some((v, p));
+            }
+        }
+        None
+    }
+}
+
+impl<P> FusedIterator for Ticks<P>
+where
+    P: Copy + PartialOrd + MapRange<f64>,
+    f64: MapRange<P>,
+{
+}
+
+/// Units per step for band and point scales.
+const UNIT: u64 = 1 << 16;
+
+/// Padding as units.
+fn padding_units(p: f64, max: f64) -> u64 {
+    let p = if p > 0.0 { p } else { 0.0 };
+    let p = if p < max { p } else { max };
+    fmath::round(p * UNIT as f64) as u64
+}
+
+/// Band scale for categorical axes.
+///
+/// The range is half-open, n bands and their padding fill
+/// range.0..range.1. Paddings are fractions of the step between
+/// bands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BandScale<T> {
+    n: usize,
+    range: (T, T),
+    inner: u64,
+    outer: u64,
+}
+
+impl<T> BandScale<T>
+where
+    T: DistributeValue,
+    u64: MapRange<T>,
+{
+    /// New band scale without padding.
+    ///
+    /// Returns None if the range is reversed.
+    pub fn new(n: usize, range: (T, T)) -> Option<Self> {
+        if range.1 < range.0 {
+            return None;
+        }
+        Some(Self {
+            n,
+            range,
+            inner: 0,
+            outer: 0,
+        })
+    }
+
+    /// Padding between bands, 0..=1.
+    pub fn padding_inner(mut self, padding: f64) -> Self {
+        self.inner = padding_units(padding, 1.0);
+        self
+    }
+
+    /// Padding before the first and after the last band, 0..=1000.
+    pub fn padding_outer(mut self, padding: f64) -> Self {
+        self.outer = padding_units(padding, 1000.0);
+        self
+    }
+
+    /// Same inner and outer padding.
+    pub fn padding(self, padding: f64) -> Self {
+        self.padding_inner(padding).padding_outer(padding)
+    }
+
+    /// Number of bands.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// No bands.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Pixel range.
+    pub fn range(&self) -> (T, T) {
+        self.range
+    }
+
+    /// Unit position to pixels.
+    #[inline]
+    fn px(&self, u: u64) -> u128 {
+        let band = UNIT - self.inner;
+        let n = self.n as u64;
+        let total = 2 * self.outer + n * band + (n - 1) * self.inner;
+        let width = T::from_wide(self.range.1.to_wide() - self.range.0.to_wide());
+        let px: T = u.map_range_unchecked((0, total), (T::from_wide(0), width));
+        self.range.0.to_wide() + px.to_wide()
+    }
+
+    /// Start pixel and width of band i.
+    ///
+    /// Returns None if i >= len().
+    pub fn band(&self, i: usize) -> Option<(T, T)> {
+        if i >= self.n {
+            return None;
+        }
+        let start = self.outer + i as u64 * UNIT;
+        let end = start + UNIT - self.inner;
+        let (start, end) = (self.px(start), self.px(end));
+        Some((T::from_wide(start), T::from_wide(end - start)))
+    }
+
+    /// Band that contains the pixel. O(log n).
+    ///
+    /// Returns None if the pixel is in the padding or outside the
+    /// range.
+    pub fn index_at(&self, px: T) -> Option<usize> {
+        // first band that ends after px
+        let (mut lo, mut hi) = (0, self.n);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let (start, width) = self.band(mid)?;
+            if start.to_wide() + width.to_wide() <= px.to_wide() {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        let (start, _) = self.band(lo)?;
+        if start <= px {
+            Some(lo)
+        } else {
+            None
+        }
+    }
+}
+
+/// Point scale for categorical axes.
+///
+/// n points spread over the range range.0..=range.1, with
+/// the outer padding as a fraction of the step between points.
+/// A single point is in the middle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointScale<T> {
+    n: usize,
+    range: (T, T),
+    outer: u64,
+}
+
+impl<T> PointScale<T>
+where
+    T: DistributeValue,
+    u64: MapRange<T>,
+{
+    /// New point scale without padding.
+    ///
+    /// Returns None if the range is reversed.
+    pub fn new(n: usize, range: (T, T)) -> Option<Self> {
+        if range.1 < range.0 {
+            return None;
+        }
+        Some(Self { n, range, outer: 0 })
+    }
+
+    /// Padding before the first and after the last point, 0..=1000.
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.outer = padding_units(padding, 1000.0);
+        self
+    }
+
+    /// Number of points.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// No points.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Pixel range.
+    pub fn range(&self) -> (T, T) {
+        self.range
+    }
+
+    /// Pixel position of point i.
+    ///
+    /// Returns None if i >= len().
+    pub fn point(&self, i: usize) -> Option<T> {
+        if i >= self.n {
+            return None;
+        }
+        let width = self.range.1.to_wide() - self.range.0.to_wide();
+        let total = 2 * self.outer + (self.n as u64 - 1) * UNIT;
+        let px = if total == 0 {
+            width / 2
+        } else {
+            let u = self.outer + i as u64 * UNIT;
+            let px: T = u.map_range_unchecked((0, total), (T::from_wide(0), T::from_wide(width)));
+            px.to_wide()
+        };
+        Some(T::from_wide(self.range.0.to_wide() + px))
+    }
+
+    /// Point closest to the pixel, ties go to the lower index.
+    /// O(log n).
+    ///
+    /// Returns None if there are no points.
+    pub fn nearest(&self, px: T) -> Option<usize> {
+        // last point at or before px
+        let (mut lo, mut hi) = (0, self.n);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.point(mid)? <= px {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo == 0 {
+            return if self.n > 0 { Some(0) } else { None };
+        }
+        let before = lo - 1;
+        match self.point(lo) {
+            Some(next) => {
+                let px = px.to_wide();
+                let d0 = px - self.point(before)?.to_wide();
+                let d1 = next.to_wide() - px;
+                Some(if d1 < d0 { lo } else { before })
+            }
+            None => Some(before),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//!
+//! Scrollbar geometry.
+//!
+//! [Scrollbar] maps a scroll offset onto the position of the thumb
+//! within a track of cells, and maps a dragged thumb back to an offset.
+//!
+//! Offset 0 puts the thumb at the start of the track, max_offset puts
+//! the end of the thumb at the end of the track. Mapping a thumb
+//! position to an offset and back gives the same thumb position.
+//!
+//! ```rust
+//! use map_range_int::scrollbar::Scrollbar;
+//!
+//! // 1000 rows, 20 visible, 20 cells track.
+//! let sb = Scrollbar::new(980, 20, 20);
+//!
+//! assert_eq!(sb.thumb(0), (0, 1));
+//! assert_eq!(sb.thumb(490), (9, 1));
+//! assert_eq!(sb.thumb(980), (19, 1));
+//!
+//! // drag the thumb to cell 19.
+//! assert_eq!(sb.offset_at(19), 980);
+//!
+//! // longer minimum thumb.
+//! let sb = sb.min_thumb(3);
+//! assert_eq!(sb.thumb(980), (17, 3));
+//! ```
+//!
+
+use crate::MapRange;
+
+/// Scrollbar geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scrollbar {
+    max_offset: usize,
+    page_len: usize,
+    track_len: usize,
+    min_thumb: usize,
+}
+
+impl Scrollbar {
+    /// New scrollbar.
+    ///
+    /// * max_offset: largest offset.
+    /// * page_len: visible part of the content, used for the thumb length.
+    /// * track_len: number of cells of the track.
+    pub fn new(max_offset: usize, page_len: usize, track_len: usize) -> Self {
+        Self {
+            max_offset,
+            page_len,
+            track_len,
+            min_thumb: 1,
+        }
+    }
+
+    /// Minimum length of the thumb. Default is 1.
+    pub fn min_thumb(mut self, min_thumb: usize) -> Self {
+        self.min_thumb = min_thumb;
+        self
+    }
+
+    /// Largest offset.
+    pub fn max_offset(&self) -> usize {
+        self.max_offset
+    }
+
+    /// Number of cells of the track.
+    pub fn track_len(&self) -> usize {
+        self.track_len
+    }
+
+    /// Length of the thumb.
+    ///
+    /// Proportional to page_len / (max_offset + page_len), at least
+    /// min_thumb and at most the track length.
+    pub fn thumb_len(&self) -> usize {
+        let content = self.max_offset.saturating_add(self.page_len);
+        let len = if content == 0 {
+            self.track_len
+        } else {
+            self.page_len
+                .min(content)
+                .map_range_unchecked((0, content), (0, self.track_len))
+        };
+        len.max(self.min_thumb).min(self.track_len)
+    }
+
+    /// Number of positions the thumb can take, minus one.
+    #[inline]
+    fn free_len(&self) -> usize {
+        self.track_len - self.thumb_len()
+    }
+
+    /// Thumb start and length for the offset. The offset is clamped
+    /// to max_offset.
+    pub fn thumb(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.max_offset);
+        let start = offset.map_range_unchecked((0, self.max_offset), (0, self.free_len()));
+        (start, self.thumb_len())
+    }
+
+    /// Offset for a thumb start position. The position is clamped
+    /// to the track.
+    ///
+    /// This rounds up, so the thumb for the result starts at
+    /// the given position.
+    pub fn offset_at(&self, thumb_start: usize) -> usize {
+        let free = self.free_len();
+        if free == 0 {
+            return 0;
+        }
+        let pos = thumb_start.min(free);
+        // ceil(pos * max / free) == max - floor((free - pos) * max / free)
+        let rev: usize = (free - pos).map_range_unchecked((0, free), (0, self.max_offset));
+        self.max_offset - rev
+    }
+
+    /// Offset for a mouse drag.
+    ///
+    /// * cell: the track cell under the mouse, relative to the track start.
+    /// * grab: where the thumb was grabbed, relative to the thumb start.
+    ///   Can be taken from the cell and the thumb start at mouse down.
+    pub fn drag(&self, cell: usize, grab: usize) -> usize {
+        self.offset_at(cell.saturating_sub(grab))
+    }
+}
@@ -0,0 +1,328 @@
+//!
+//! Slider between a track of cells and a stepped value range.
+//!
+//! [Slider] snaps values to `min + i * step`. The values are always
+//! calculated from the step index `i`, so float steps don't accumulate
+//! errors. min and max are always reachable, if the range is not
+//! a multiple of the step the last step is shorter.
+//!
+//! The track positions are inclusive 0..=track_len-1, a mouse
+//! position snaps to the step whose knob is closest.
+//!
+//! ```rust
+//! use map_range_int::slider::Slider;
+//!
+//! let sl = Slider::new((0., 1.), 0.05, 21).expect("valid");
+//! assert_eq!(sl.steps(), 20);
+//! assert_eq!(sl.snap(0.33), 0.35);
+//! assert_eq!(sl.step_up(0.95), 1.0);
+//! assert_eq!(sl.step_up(1.0), 1.0);
+//! assert_eq!(sl.value_at(7), 0.35);
+//! assert_eq!(sl.position(0.35), 7);
+//!
+//! let sl = Slider::new((0u8, 99), 5, 100).expect("valid").page(4);
+//! assert_eq!(sl.snap(42), 40);
+//! assert_eq!(sl.page_up(40), 60);
+//! assert_eq!(sl.page_up(90), 99);
+//! assert_eq!(sl.step_down(99), 95);
+//! ```
+//!
+
+use crate::MapRange;
+
+/// Values for a [Slider].
+pub trait SliderValue: Copy + PartialOrd {
+    /// Number of steps from min to max, rounded up.
+    ///
+    /// Returns None for a reversed range, a step <= 0 or
+    /// non-finite values.
+    fn steps(min: Self, max: Self, step: Self) -> Option<u64>;
+
+    /// Value of step i, the last step is max.
+    fn nth(min: Self, max: Self, step: Self, i: u64) -> Self;
+
+    /// Step index closest to v. Values out of bounds are clamped.
+    fn index(min: Self, max: Self, step: Self, v: Self) -> u64;
+}
+
+macro_rules! i_slider_value {
+    ($ty:ty) => {
+        impl SliderValue for $ty {
+            fn steps(min: Self, max: Self, step: Self) -> Option<u64> {
+                if max < min || step <= 0 {
+                    return None;
+                }
+                let diff = max as i128 - min as i128;
+                let step = step as i128;
+                Some(((diff + step - 1) / step) as u64)
+            }
+
+            fn nth(min: Self, max: Self, step: Self, i: u64) -> Self {
+                let v = min as i128 + i as i128 * step as i128;
+                if v >= max as i128 {
+                    max
+                } else {
+                    v as $ty
+                }
+            }
+
+            fn index(min: Self, max: Self, step: Self, v: Self) -> u64 {
+                let v = if v < min {
+                    min
+                } else if v > max {
+                    max
+                } else {
+                    v
+                };
+                let d = v as i128 - min as i128;
+                let diff = max as i128 - min as i128;
+                let step = step as i128;
+                if d >= diff {
+                    return ((diff + step - 1) / step) as u64;
+                }
+                let q = d / step;
+                let lo = q * step;
+                let hi = (lo + step).min(diff);
+                if hi - d <= d - lo {
+                    q as u64 + 1
+                } else {
+                    q as u64
+                }
+            }
+        }
+    };
+}
+
+i_slider_value!(u8);
+i_slider_value!(u16);
+i_slider_value!(u32);
+i_slider_value!(u64);
+i_slider_value!(usize);
+i_slider_value!(i8);
+i_slider_value!(i16);
+i_slider_value!(i32);
+i_slider_value!(i64);
+i_slider_value!(isize);
+
+macro_rules! f_slider_value {
+    ($ty:ty) => {
+        impl SliderValue for $ty {
+            fn steps(min: Self, max: Self, step: Self) -> Option<u64> {
+                if !(min.is_finite() && max.is_finite() && step.is_finite()) {
+                    return None;
+                }
+                if max < min || step <= 0.0 {
+                    return None;
+                }
+                let n = (max as f64 - min as f64) / step as f64;
+                if n >= u64::MAX as f64 {
+                    return None;
+                }
+                // 0.7 / 0.1 == 6.999999999999999. the tolerance must
+                // match the precision of $ty, 0.7f32 is off by 1e-8.
+                let r = crate::fmath::round(n);
+                let scale = r + (min as f64).abs().max((max as f64).abs()) / step as f64;
+                if (n - r).abs() <= scale * 4.0 * <$ty>::EPSILON as f64 {
+                    Some(r as u64)
+                } else {
+                    Some(crate::fmath::floor(n) as u64 + 1)
+                }
+            }
+
+            fn nth(min: Self, max: Self, step: Self, i: u64) -> Self {
+                // for steps like 0.05 i / 20 gives the nearest float
+                // to the decimal value, i * 0.05 often does not.
+                let inv = 1.0 / step as f64;
+                let k = crate::fmath::round(inv);
+                let d = if k >= 1.0 && (inv - k).abs() <= k * 4.0 * <$ty>::EPSILON as f64 {
+                    i as f64 / k
+                } else {
+                    i as f64 * step as f64
+                };
+                let v = (min as f64 + d) as $ty;
+                if i >= Self::steps(min, max, step).unwrap_or(0) || v >= max {
+                    max
+                } else {
+                    v
+                }
+            }
+
+            fn index(min: Self, max: Self, step: Self, v: Self) -> u64 {
+                let n = Self::steps(min, max, step).unwrap_or(0);
+                if v <= min || v.is_nan() {
+                    return 0;
+                }
+                if v >= max {
+                    return n;
+                }
+                let q = (crate::fmath::floor((v as f64 - min as f64) / step as f64) as u64).min(n);
+                if q >= n {
+                    return n;
+                }
+                let lo = Self::nth(min, max, step, q);
+                let hi = Self::nth(min, max, step, q + 1);
+                if hi - v <= v - lo {
+                    q + 1
+                } else {
+                    q
+                }
+            }
+        }
+    };
+}
+
+f_slider_value!(f32);
+f_slider_value!(f64);
+
+/// Slider model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Slider<T> {
+    min: T,
+    max: T,
+    step: T,
+    steps: u64,
+    track_len: usize,
+    page: u64,
+}
+
+impl<T: SliderValue> Slider<T> {
+    /// New slider.
+    ///
+    /// * range: min and max value.
+    /// * step: step size, must be > 0.
+    /// * track_len: number of cells/pixels of the track.
+    ///
+    /// Returns None for a reversed range or an invalid step.
+    pub fn new(range: (T, T), step: T, track_len: usize) -> Option<Self> {
+        let steps = T::steps(range.0, range.1, step)?;
+        Some(Self {
+            min: range.0,
+            max: range.1,
+            step,
+            steps,
+            track_len,
+            page: 10,
+        })
+    }
+
+    /// Steps for page up/down. Default is 10.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Value range.
+    pub fn range(&self) -> (T, T) {
+        (self.min, self.max)
+    }
+
+    /// Step size.
+    pub fn step(&self) -> T {
+        self.step
+    }
+
+    /// Number of steps from min to max. There are steps + 1 values.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Number of cells/pixels of the track.
+    pub fn track_len(&self) -> usize {
+        self.track_len
+    }
+
+    /// Set the track length, for a resize.
+    pub fn set_track_len(&mut self, track_len: usize) {
+        self.track_len = track_len;
+    }
+
+    /// Step index of the value closest to v.
+    pub fn index_of(&self, v: T) -> u64 {
+        T::index(self.min, self.max, self.step, v)
+    }
+
+    /// Value for a step index. Clamped to max.
+    pub fn value_of(&self, index: u64) -> T {
+        T::nth(self.min, self.max, self.step, index.min(self.steps))
+    }
+
+    /// Snap a value to the closest step. Clamps to min/max.
+    pub fn snap(&self, v: T) -> T {
+        self.value_of(self.index_of(v))
+    }
+
+    /// Move by n steps from the snapped value.
+    pub fn step_by(&self, v: T, n: i64) -> T {
+        let i = self.index_of(v);
+        let i = if n < 0 {
+            i.saturating_sub(n.unsigned_abs())
+        } else {
+            i.saturating_add(n as u64)
+        };
+        self.value_of(i)
+    }
+
+    /// One step up.
+    pub fn step_up(&self, v: T) -> T {
+        self.step_by(v, 1)
+    }
+
+    /// One step down.
+    pub fn step_down(&self, v: T) -> T {
+        self.step_by(v, -1)
+    }
+
+    /// One page up.
+    pub fn page_up(&self, v: T) -> T {
+        self.value_of(self.index_of(v).saturating_add(self.page))
+    }
+
+    /// One page down.
+    pub fn page_down(&self, v: T) -> T {
+        self.value_of(self.index_of(v).saturating_sub(self.page))
+    }
+
+    /// Last track position.
+    #[inline]
+    fn last_pos(&self) -> usize {
+        self.track_len.saturating_sub(1)
+    }
+
+    /// Track position of a step index.
+    #[inline]
+    fn pos_of(&self, index: u64) -> usize {
+        index.map_range_unchecked((0, self.steps), (0, self.last_pos()))
+    }
+
+    /// Track position of the knob for the value.
+    pub fn position(&self, v: T) -> usize {
+        self.pos_of(self.index_of(v))
+    }
+
+    /// Step index for a track position. The position is clamped
+    /// to the track.
+    pub fn index_at(&self, pos: usize) -> u64 {
+        let last = self.last_pos();
+        if last == 0 {
+            return 0;
+        }
+        let pos = pos.min(last);
+        let q: u64 = pos.map_range_unchecked((0, last), (0, self.steps));
+        if q >= self.steps {
+            return self.steps;
+        }
+        // pos_of(q) <= pos <= pos_of(q + 1)
+        let lo = self.pos_of(q);
+        let hi = self.pos_of(q + 1);
+        if hi - pos < pos - lo {
+            q + 1
+        } else {
+            q
+        }
+    }
+
+    /// Value for a track position, snapped to the closest step.
+    pub fn value_at(&self, pos: usize) -> T {
+        self.value_of(self.index_at(pos))
+    }
+}
@@ -0,0 +1,161 @@
+//!
+//! Sparklines from numeric series.
+//!
+//! Each sample is mapped onto one of the 8 [BLOCKS], or onto
+//! 4 dot rows of a braille character with two samples per
+//! character.
+//!
+//! The range is either given or taken from the min and max of the
+//! samples. Samples out of bounds are clamped. If the range is empty,
+//! e.g. all samples are equal, every sample is drawn at mid-level.
+//!
+//! With the feature `alloc` there are `sparkline()` and
+//! `braille()` returning a String, otherwise use the `write_`
+//! functions with any [core::fmt::Write].
+//!
+//! ```rust
+//! use map_range_int::sparkline::{write_braille, write_sparkline};
+//!
+//! let mut s = String::new();
+//! write_sparkline(&mut s, &[0u8, 1, 2, 3, 4, 5, 6, 7], None).expect("write");
+//! assert_eq!(s, "▁▂▃▄▅▆▇█");
+//!
+//! let mut s = String::new();
+//! write_sparkline(&mut s, &[5., 5., 5.], None).expect("write");
+//! assert_eq!(s, "▄▄▄");
+//!
+//! let mut s = String::new();
+//! write_sparkline(&mut s, &[0, 50, 100], Some((0, 200))).expect("write");
+//! assert_eq!(s, "▁▃▅");
+//!
+//! let mut s = String::new();
+//! write_braille(&mut s, &[0u8, 1, 2, 3, 3], None).expect("write");
+//! assert_eq!(s, "⣠⣾⡇");
+//! ```
+//!
+
+use crate::MapRange;
+use core::fmt;
+
+/// Block glyphs from low to high.
+pub const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Braille dots of the left column, from the bottom up.
+const BRAILLE_LEFT: [u32; 4] = [0x40, 0x04, 0x02, 0x01];
+/// Braille dots of the right column, from the bottom up.
+const BRAILLE_RIGHT: [u32; 4] = [0x80, 0x20, 0x10, 0x08];
+
+/// Min and max of the samples.
+///
+/// Returns None if there are no samples.
+pub fn auto_range<T>(samples: &[T]) -> Option<(T, T)>
+where
+    T: Copy + PartialOrd,
+{
+    let (&first, rest) = samples.split_first()?;
+    let mut range = (first, first);
+    for &v in rest {
+        if v < range.0 {
+            range.0 = v;
+        }
+        if v > range.1 {
+            range.1 = v;
+        }
+    }
+    Some(range)
+}
+
+/// Level 0..levels for the value.
+///
+/// Each level covers an equal part of the range. Values out of
+/// bounds are clamped, for an empty range this gives the mid-level.
+pub fn level<T>(v: T, range: (T, T), levels: u8) -> u8
+where
+    T: MapRange<u8> + Copy,
+{
+    if levels == 0 {
+        return 0;
+    }
+    if range.1 <= range.0 {
+        return (levels - 1) / 2;
+    }
+    if v <= range.0 {
+        0
+    } else if v >= range.1 {
+        levels - 1
+    } else {
+        let l: u8 = v.map_range_unchecked(range, (0, levels));
+        l.min(levels - 1)
+    }
+}
+
+/// Write a block sparkline.
+///
+/// Uses [auto_range] if range is None.
+pub fn write_sparkline<W, T>(w: &mut W, samples: &[T], range: Option<(T, T)>) -> fmt::Result
+where
+    W: fmt::Write,
+    T: MapRange<u8> + Copy,
+{
+    let Some(range) = range.or_else(|| auto_range(samples)) else {
+        return Ok(());
+    };
+    for &v in samples {
+        w.write_char(BLOCKS[level(v, range, 8) as usize])?;
+    }
+    Ok(())
+}
+
+/// Write a braille sparkline, two samples per character.
+///
+/// Uses [auto_range] if range is None.
+pub fn write_braille<W, T>(w: &mut W, samples: &[T], range: Option<(T, T)>) -> fmt::Result
+where
+    W: fmt::Write,
+    T: MapRange<u8> + Copy,
+{
+    let Some(range) = range.or_else(|| auto_range(samples)) else {
+        return Ok(());
+    };
+    let bar = |v: T, dots: &[u32; 4]| {
+        let l = level(v, range, 4) as usize;
+        dots[..=l].iter().fold(0, |acc, d| acc | d)
+    };
+    for pair in samples.chunks(2) {
+        let mut bits = bar(pair[0], &BRAILLE_LEFT);
+        if let Some(&v) = pair.get(1) {
+            bits |= bar(v, &BRAILLE_RIGHT);
+        }
+        // all values are valid braille patterns.
+        w.write_char(char::from_u32(0x2800 + bits).unwrap_or(' '))?;
+    }
+    Ok(())
+}
+
+/// Block sparkline.
+///
+/// Uses [auto_range] if range is None.
+#[cfg(feature = "alloc")]
+pub fn sparkline<T>(samples: &[T], range: Option<(T, T)>) -> alloc::string::String
+where
+    T: MapRange<u8> + Copy,
+{
+    let mut s = alloc::string::String::with_capacity(samples.len() * 3);
+    // writing to a String can't fail.
+    _ = write_sparkline(&mut s, samples, range);
+    s
+}
+
+/// Braille sparkline, two samples per character.
+///
+/// Uses [auto_range] if range is None.
+#[cfg(feature = "alloc")]
+pub fn braille<T>(samples: &[T], range: Option<(T, T)>) -> alloc::string::String
+where
+    T: MapRange<u8> + Copy,
+{
+    let mut s = alloc::string::String::with_capacity(samples.len().div_ceil(2) * 3);
+    // writing to a String can't fail.
+    _ = write_braille(&mut s, samples, range);
+    s
+}
@@ -0,0 +1,228 @@
+//!
+//! Monotone cubic spline interpolation.
+//!
+//! [MonotoneCubic] interpolates a table of (src, dst) breakpoints
+//! with a cubic Hermite spline. The tangents are chosen after
+//! Fritsch-Carlson/Fritsch-Butland, so the curve passes through every
+//! breakpoint and doesn't overshoot between them. A monotone table
+//! gives a monotone curve.
+//!
+//! The tangents are calculated locally from the neighbouring
+//! breakpoints, so the table is all the storage needed.
+//!
+//! ```rust
+//! use map_range_int::spline::MonotoneCubic;
+//!
+//! let table = [(0., 0.), (64., 10.), (128., 200.), (255., 255.)];
+//! let spline = MonotoneCubic::new(&table).expect("valid table");
+//!
+//! assert_eq!(spline.map(64.), Some(10.));
+//! assert_eq!(spline.map(255.), Some(255.));
+//! assert_eq!(spline.map(256.), None);
+//!
+//! // bake a lookup table for u8 sources.
+//! let mut lut = [0u8; 256];
+//! spline.bake(&mut lut);
+//! assert_eq!(lut[128], 200);
+//! ```
+//!
+
+/// Monotone cubic Hermite spline over a breakpoint table.
+#[derive(Debug, Clone, Copy)]
+pub struct MonotoneCubic<'a, T> {
+    points: &'a [(T, T)],
+}
+
+/// Values for the breakpoint table and the baked lookup table.
+///
+/// Integer breakpoints are interpolated with f64 and the result
+/// is rounded.
+pub trait SplineValue: Copy {
+    /// Convert, rounds to nearest and saturates for integers.
+    fn from_f64(v: f64) -> Self;
+}
+
+macro_rules! i_spline_value {
+    ($ty:ty) => {
+        impl SplineValue for $ty {
+            #[inline]
+            fn from_f64(v: f64) -> Self {
+                // as saturates
+                if v < 0.0 {
+                    (v - 0.5) as $ty
+                } else {
+                    (v + 0.5) as $ty
+                }
+            }
+        }
+    };
+}
+
+i_spline_value!(u8);
+i_spline_value!(u16);
+i_spline_value!(u32);
+i_spline_value!(u64);
+i_spline_value!(usize);
+i_spline_value!(i8);
+i_spline_value!(i16);
+i_spline_value!(i32);
+i_spline_value!(i64);
+i_spline_value!(isize);
+
+impl SplineValue for f64 {
+    #[inline]
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+}
+
+impl SplineValue for f32 {
+    #[inline]
+    fn from_f64(v: f64) -> Self {
+        v as f32
+    }
+}
+
+/// Tangent at an interior point from the neighbouring secants.
+#[inline]
+fn tangent_inner(h0: f64, d0: f64, h1: f64, d1: f64) -> f64 {
+    if d0 * d1 <= 0.0 {
+        return 0.0;
+    }
+    // weighted harmonic mean
+    let w1 = 2.0 * h1 + h0;
+    let w2 = h1 + 2.0 * h0;
+    (w1 + w2) / (w1 / d0 + w2 / d1)
+}
+
+/// Tangent at an end point, h0/d0 is the secant next to the end.
+#[inline]
+fn tangent_edge(h0: f64, d0: f64, h1: f64, d1: f64) -> f64 {
+    let m = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+    if m * d0 <= 0.0 {
+        0.0
+    } else if d0 * d1 <= 0.0 && m.abs() > 3.0 * d0.abs() {
+        3.0 * d0
+    } else {
+        m
+    }
+}
+
+impl<'a, T> MonotoneCubic<'a, T>
+where
+    T: SplineValue + Into<f64> + PartialOrd,
+{
+    /// New spline.
+    ///
+    /// Needs at least two points, and the source values must be
+    /// strictly increasing. Returns None otherwise.
+    pub fn new(points: &'a [(T, T)]) -> Option<Self> {
+        if points.len() < 2 {
+            return None;
+        }
+        if !points.windows(2).all(|w| w[0].0 < w[1].0) {
+            return None;
+        }
+        Some(Self { points })
+    }
+
+    /// Source range.
+    #[inline]
+    pub fn range(&self) -> (T, T) {
+        (self.points[0].0, self.points[self.points.len() - 1].0)
+    }
+
+    /// Interpolate.
+    ///
+    /// Returns None if x is out of bounds for the table.
+    pub fn map(&self, x: T) -> Option<T> {
+        let range = self.range();
+        if x < range.0 || x > range.1 {
+            return None;
+        }
+        Some(self.map_clamped(x))
+    }
+
+    /// Interpolate. Values out of bounds are clamped to the
+    /// first/last point.
+    pub fn map_clamped(&self, x: T) -> T {
+        let p = self.points;
+        // first point > x
+        let k = p.partition_point(|v| v.0 <= x);
+        if k == 0 {
+            p[0].1
+        } else if p[k - 1].0 == x || k == p.len() {
+            p[k - 1].1
+        } else {
+            T::from_f64(self.interpolate(k - 1, x.into()))
+        }
+    }
+
+    /// Bake a lookup table.
+    ///
+    /// Sets `lut[i]` to the clamped result for source `i`,
+    /// so afterward `lut[x as usize]` can replace map() for
+    /// u8/u16 sources. Integer values are rounded.
+    pub fn bake<Out: SplineValue>(&self, lut: &mut [Out]) {
+        let p = self.points;
+        let mut k = 0;
+        for (i, v) in lut.iter_mut().enumerate() {
+            let x = i as f64;
+            while k < p.len() && p[k].0.into() <= x {
+                k += 1;
+            }
+            *v = if k == 0 {
+                Out::from_f64(p[0].1.into())
+            } else if p[k - 1].0.into() == x || k == p.len() {
+                Out::from_f64(p[k - 1].1.into())
+            } else {
+                Out::from_f64(self.interpolate(k - 1, x))
+            };
+        }
+    }
+
+    fn tangent(&self, k: usize) -> f64 {
+        let p = self.points;
+        let secant = |i: usize| {
+            let h = p[i + 1].0.into() - p[i].0.into();
+            (h, (p[i + 1].1.into() - p[i].1.into()) / h)
+        };
+
+        let n = p.len();
+        if n == 2 {
+            secant(0).1
+        } else if k == 0 {
+            let (h0, d0) = secant(0);
+            let (h1, d1) = secant(1);
+            tangent_edge(h0, d0, h1, d1)
+        } else if k == n - 1 {
+            let (h0, d0) = secant(n - 2);
+            let (h1, d1) = secant(n - 3);
+            tangent_edge(h0, d0, h1, d1)
+        } else {
+            let (h0, d0) = secant(k - 1);
+            let (h1, d1) = secant(k);
+            tangent_inner(h0, d0, h1, d1)
+        }
+    }
+
+    /// Hermite interpolation between point k and k+1.
+    fn interpolate(&self, k: usize, x: f64) -> f64 {
+        let p = self.points;
+        let m0 = self.tangent(k);
+        let m1 = self.tangent(k + 1);
+        let (x0, y0): (f64, f64) = (p[k].0.into(), p[k].1.into());
+        let (x1, y1): (f64, f64) = (p[k + 1].0.into(), p[k + 1].1.into());
+
+        let h = x1 - x0;
+        let s = (x - x0) / h;
+        let s2 = s * s;
+        let s3 = s2 * s;
+        let h10 = s3 - 2.0 * s2 + s;
+        let h01 = -2.0 * s3 + 3.0 * s2;
+        let h11 = s3 - s2;
+
+        // h00 * y0 + h01 * y1 rearranged, keeps flat segments flat.
+        y0 + h01 * (y1 - y0) + h * (h10 * m0 + h11 * m1)
+    }
+}
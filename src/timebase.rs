@@ -0,0 +1,126 @@
+//!
+//! Rescale timestamps between rational timebases.
+//!
+//! Same semantics as av_rescale_rnd() and av_rescale_q_rnd() from
+//! FFmpeg, calculated with i128 so there are no intermediate
+//! overflows.
+//!
+//! Errors and results that don't fit i64 give [NOPTS], i64::MIN,
+//! which is AV_NOPTS_VALUE in FFmpeg.
+//!
+//! ```rust
+//! use map_range_int::timebase::{rescale, Ratio, Rounding, NOPTS};
+//!
+//! let mpeg = Ratio::new(1, 90000);
+//! let ms = Ratio::new(1, 1000);
+//!
+//! assert_eq!(rescale(90000, mpeg, ms, Rounding::NEAR_INF), 1000);
+//! assert_eq!(rescale(45, mpeg, ms, Rounding::NEAR_INF), 1);
+//! assert_eq!(rescale(45, mpeg, ms, Rounding::DOWN), 0);
+//! assert_eq!(rescale(-45, mpeg, ms, Rounding::DOWN), -1);
+//!
+//! // sentinel timestamps pass through.
+//! let rnd = Rounding::NEAR_INF.pass_minmax();
+//! assert_eq!(rescale(NOPTS, mpeg, ms, rnd), NOPTS);
+//! assert_eq!(rescale(i64::MAX, mpeg, ms, rnd), i64::MAX);
+//! ```
+//!
+
+/// Undefined timestamp, AV_NOPTS_VALUE.
+pub const NOPTS: i64 = i64::MIN;
+
+/// Rational number, num/den.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    /// Numerator.
+    pub num: i32,
+    /// Denominator.
+    pub den: i32,
+}
+
+impl Ratio {
+    /// New ratio.
+    pub const fn new(num: i32, den: i32) -> Self {
+        Self { num, den }
+    }
+}
+
+/// Rounding mode, AVRounding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rounding {
+    mode: u8,
+    pass_minmax: bool,
+}
+
+impl Rounding {
+    /// Round toward zero.
+    pub const ZERO: Rounding = Rounding::mode(0);
+    /// Round away from zero.
+    pub const INF: Rounding = Rounding::mode(1);
+    /// Round toward -infinity.
+    pub const DOWN: Rounding = Rounding::mode(2);
+    /// Round toward +infinity.
+    pub const UP: Rounding = Rounding::mode(3);
+    /// Round to nearest and halfway cases away from zero.
+    pub const NEAR_INF: Rounding = Rounding::mode(5);
+
+    const fn mode(mode: u8) -> Self {
+        Self {
+            mode,
+            pass_minmax: false,
+        }
+    }
+
+    /// Pass i64::MIN and i64::MAX through unchanged, instead of
+    /// rescaling them. AV_ROUND_PASS_MINMAX.
+    pub const fn pass_minmax(mut self) -> Self {
+        self.pass_minmax = true;
+        self
+    }
+
+    /// Swap DOWN and UP, for negative values.
+    const fn mirror(mut self) -> Self {
+        self.mode ^= (self.mode >> 1) & 1;
+        self
+    }
+}
+
+/// Rescale a * b / c with rounding, av_rescale_rnd().
+///
+/// Returns [NOPTS] if c <= 0, b < 0 or the result doesn't fit i64.
+pub fn rescale_rnd(a: i64, b: i64, c: i64, rnd: Rounding) -> i64 {
+    if c <= 0 || b < 0 {
+        return NOPTS;
+    }
+    if rnd.pass_minmax && (a == i64::MIN || a == i64::MAX) {
+        return a;
+    }
+    if a < 0 {
+        // wraps for NOPTS like FFmpeg does. -i64::MAX is no
+        // min/max value, FFmpeg drops the flag here too.
+        let rnd = Rounding {
+            pass_minmax: false,
+            ..rnd.mirror()
+        };
+        return rescale_rnd(a.max(-i64::MAX).wrapping_neg(), b, c, rnd).wrapping_neg();
+    }
+
+    let r = match rnd.mode {
+        5 => c / 2,
+        1 | 3 => c - 1,
+        _ => 0,
+    };
+    let v = (a as i128 * b as i128 + r as i128) / c as i128;
+    i64::try_from(v).unwrap_or(NOPTS)
+}
+
+/// Rescale a timestamp from one timebase to another with rounding,
+/// av_rescale_q_rnd().
+///
+/// Returns [NOPTS] for invalid timebases or if the result doesn't
+/// fit i64.
+pub fn rescale(ts: i64, from: Ratio, to: Ratio, rnd: Rounding) -> i64 {
+    let b = from.num as i64 * to.den as i64;
+    let c = to.num as i64 * from.den as i64;
+    rescale_rnd(ts, b, c, rnd)
+}
@@ -0,0 +1,115 @@
+//!
+//! Unbiased random integers in a range.
+//!
+//! `rnd.map_range((0, u32::MAX), (lo, hi))` is slightly biased
+//! whenever the size of the range doesn't divide 2^32, and pays for
+//! a division. [uniform_in] uses Lemire's nearly-divisionless method
+//! instead: the random bits are multiplied with the size of the range,
+//! the high half is the result. The few values that would cause the
+//! bias are rejected and redrawn with more bits from the closure, which
+//! is rarely called for small ranges.
+//!
+//! The range includes both ends and may be reversed, the result is
+//! between the two values either way.
+//!
+//! ```rust
+//! use map_range_int::uniform::uniform_in;
+//!
+//! let mut state = 0x9e37_79b9_7f4a_7c15u64;
+//! let mut rnd = move || {
+//!     // xorshift
+//!     state ^= state << 13;
+//!     state ^= state >> 7;
+//!     state ^= state << 17;
+//!     state
+//! };
+//!
+//! let bits = rnd();
+//! let die: u8 = uniform_in(bits, (1, 6), &mut rnd);
+//! assert!((1..=6).contains(&die));
+//!
+//! let bits = rnd();
+//! let v: i16 = uniform_in(bits, (10, -10), &mut rnd);
+//! assert!((-10..=10).contains(&v));
+//! ```
+//!
+
+/// Values for [uniform_in].
+pub trait UniformValue: Copy {
+    /// Lowest value and the number of values - 1, for
+    /// either order of the range.
+    fn span(range: (Self, Self)) -> (Self, u64);
+
+    /// a + off.
+    fn offset(a: Self, off: u64) -> Self;
+}
+
+macro_rules! i_uniform_value {
+    ($ty:ty) => {
+        impl UniformValue for $ty {
+            #[inline(always)]
+            fn span(range: (Self, Self)) -> (Self, u64) {
+                let (lo, hi) = if range.0 <= range.1 {
+                    (range.0, range.1)
+                } else {
+                    (range.1, range.0)
+                };
+                (lo, (hi as i128 - lo as i128) as u64)
+            }
+
+            #[inline(always)]
+            fn offset(a: Self, off: u64) -> Self {
+                (a as i128 + off as i128) as $ty
+            }
+        }
+    };
+}
+
+i_uniform_value!(u8);
+i_uniform_value!(u16);
+i_uniform_value!(u32);
+i_uniform_value!(u64);
+i_uniform_value!(usize);
+i_uniform_value!(i8);
+i_uniform_value!(i16);
+i_uniform_value!(i32);
+i_uniform_value!(i64);
+i_uniform_value!(isize);
+
+/// Uniform random value in 0..=max.
+///
+/// Lemire, "Fast Random Integer Generation in an Interval", 2019.
+#[inline]
+pub fn uniform_u64<F>(bits: u64, max: u64, mut more: F) -> u64
+where
+    F: FnMut() -> u64,
+{
+    let Some(s) = max.checked_add(1) else {
+        // full range, every value is fine.
+        return bits;
+    };
+    let mut m = bits as u128 * s as u128;
+    if (m as u64) < s {
+        // 2^64 mod s, without a 128-bit division.
+        let t = s.wrapping_neg() % s;
+        while (m as u64) < t {
+            m = more() as u128 * s as u128;
+        }
+    }
+    (m >> 64) as u64
+}
+
+/// Uniform random value between range.0 and range.1, both included.
+/// The range may be reversed.
+///
+/// bits are 64 random bits, more is called for new random bits
+/// if bits has to be rejected.
+#[inline]
+pub fn uniform_in<T, F>(bits: u64, range: (T, T), more: F) -> T
+where
+    T: UniformValue,
+    F: FnMut() -> u64,
+{
+    let (lo, max) = T::span(range);
+    T::offset(lo, uniform_u64(bits, max, more))
+}
@@ -0,0 +1,223 @@
+//!
+//! 2D viewport between world and screen coordinates.
+//!
+//! [Viewport] maps a world rectangle (f64) onto a rectangle of
+//! pixels or terminal cells. The screen ranges are inclusive like the
+//! target ranges of [MapRange], and the rounding is the same everywhere:
+//!
+//! * A screen cell covers a half-open slice of the world, world to
+//!   screen rounds down in screen coordinates.
+//! * The far edge of the world maps to the last cell.
+//! * Screen to world gives the centre of the cell, so a round trip
+//!   always hits the same cell again.
+//!
+//! ```rust
+//! use map_range_int::viewport::Viewport;
+//!
+//! // world y goes up, screen rows go down.
+//! let mut vp = Viewport::new((0., 100.), (0., 50.), (0u16, 99), (0u16, 49))
+//!     .expect("valid")
+//!     .flip_y(true);
+//!
+//! assert_eq!(vp.to_screen(0., 0.), Some((0, 49)));
+//! assert_eq!(vp.to_screen(100., 50.), Some((99, 0)));
+//! assert_eq!(vp.to_world(10, 49), Some((10.5, 0.5)));
+//!
+//! // zoom in 2x around the mouse cursor, the world under it stays put.
+//! let before = vp.to_world(30, 20);
+//! vp.zoom_at(30, 20, 2.);
+//! assert_eq!(vp.to_world(30, 20), before);
+//! assert_eq!(vp.world_x(), (15.25, 65.25));
+//! ```
+//!
+
+use crate::fmath;
+use crate::MapRange;
+
+/// Non-empty world range.
+#[inline]
+fn is_valid(r: (f64, f64)) -> bool {
+    r.0 < r.1 && (r.1 - r.0).is_finite()
+}
+
+/// Viewport between world and screen coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport<S> {
+    world_x: (f64, f64),
+    world_y: (f64, f64),
+    screen_x: (S, S),
+    screen_y: (S, S),
+    flip_y: bool,
+}
+
+impl<S> Viewport<S>
+where
+    S: Copy + PartialOrd + Into<f64> + TryFrom<i64>,
+{
+    /// New viewport.
+    ///
+    /// The world ranges must not be empty, the screen ranges are
+    /// inclusive and must not be reversed. Returns None otherwise.
+    pub fn new(
+        world_x: (f64, f64),
+        world_y: (f64, f64),
+        screen_x: (S, S),
+        screen_y: (S, S),
+    ) -> Option<Self> {
+        if !is_valid(world_x) || !is_valid(world_y) {
+            return None;
+        }
+        if screen_x.1 < screen_x.0 || screen_y.1 < screen_y.0 {
+            return None;
+        }
+        Some(Self {
+            world_x,
+            world_y,
+            screen_x,
+            screen_y,
+            flip_y: false,
+        })
+    }
+
+    /// World y grows upwards while the screen grows downwards.
+    pub fn flip_y(mut self, flip_y: bool) -> Self {
+        self.flip_y = flip_y;
+        self
+    }
+
+    /// Visible world x range.
+    pub fn world_x(&self) -> (f64, f64) {
+        self.world_x
+    }
+
+    /// Visible world y range.
+    pub fn world_y(&self) -> (f64, f64) {
+        self.world_y
+    }
+
+    /// Set the visible world. Ignores empty ranges.
+    pub fn set_world(&mut self, world_x: (f64, f64), world_y: (f64, f64)) {
+        if is_valid(world_x) && is_valid(world_y) {
+            self.world_x = world_x;
+            self.world_y = world_y;
+        }
+    }
+
+    /// Screen x range.
+    pub fn screen_x(&self) -> (S, S) {
+        self.screen_x
+    }
+
+    /// Screen y range.
+    pub fn screen_y(&self) -> (S, S) {
+        self.screen_y
+    }
+
+    /// Set the screen area, for a resize. Ignores reversed ranges.
+    pub fn set_screen(&mut self, screen_x: (S, S), screen_y: (S, S)) {
+        if screen_x.0 <= screen_x.1 && screen_y.0 <= screen_y.1 {
+            self.screen_x = screen_x;
+            self.screen_y = screen_y;
+        }
+    }
+
+    /// Number of cells.
+    #[inline]
+    fn cells(screen: (S, S)) -> f64 {
+        screen.1.into() - screen.0.into() + 1.0
+    }
+
+    /// World units per cell.
+    pub fn scale(&self) -> (f64, f64) {
+        (
+            (self.world_x.1 - self.world_x.0) / Self::cells(self.screen_x),
+            (self.world_y.1 - self.world_y.0) / Self::cells(self.screen_y),
+        )
+    }
+
+    /// World to fractional screen coordinates, relative to the first
+    /// cell. Not bounded by the screen, meant for drawing lines that
+    /// leave the screen.
+    pub fn to_screen_f(&self, x: f64, y: f64) -> (f64, f64) {
+        let sx = x.map_range_unchecked(self.world_x, (0., Self::cells(self.screen_x)));
+        let sy = if self.flip_y {
+            let y = self.world_y.1 - (y - self.world_y.0);
+            y.map_range_unchecked(self.world_y, (0., Self::cells(self.screen_y)))
+        } else {
+            y.map_range_unchecked(self.world_y, (0., Self::cells(self.screen_y)))
+        };
+        (self.screen_x.0.into() + sx, self.screen_y.0.into() + sy)
+    }
+
+    /// World to screen coordinates.
+    ///
+    /// Returns None if the point is not on screen.
+    pub fn to_screen(&self, x: f64, y: f64) -> Option<(S, S)> {
+        if x < self.world_x.0 || x > self.world_x.1 || y < self.world_y.0 || y > self.world_y.1 {
+            return None;
+        }
+
+        let (sx, sy) = self.to_screen_f(x, y);
+        let sx = (fmath::floor(sx) as i64).min(self.screen_x.1.into() as i64);
+        let sy = (fmath::floor(sy) as i64).min(self.screen_y.1.into() as i64);
+
+        Some((S::try_from(sx).ok()?, S::try_from(sy).ok()?))
+    }
+
+    /// Screen to world coordinates. Gives the centre of the cell.
+    ///
+    /// Returns None if the cell is not in the screen area.
+    pub fn to_world(&self, sx: S, sy: S) -> Option<(f64, f64)> {
+        if sx < self.screen_x.0
+            || sx > self.screen_x.1
+            || sy < self.screen_y.0
+            || sy > self.screen_y.1
+        {
+            return None;
+        }
+        Some(self.to_world_f(sx.into() + 0.5, sy.into() + 0.5))
+    }
+
+    /// Fractional screen to world coordinates. Not bounded by the screen.
+    pub fn to_world_f(&self, sx: f64, sy: f64) -> (f64, f64) {
+        let sx = sx - self.screen_x.0.into();
+        let sy = sy - self.screen_y.0.into();
+        let x = sx.map_range_unchecked((0., Self::cells(self.screen_x)), self.world_x);
+        let y = sy.map_range_unchecked((0., Self::cells(self.screen_y)), self.world_y);
+        if self.flip_y {
+            (x, self.world_y.1 - (y - self.world_y.0))
+        } else {
+            (x, y)
+        }
+    }
+
+    /// Zoom by factor, keeping the world under the screen cell
+    /// (sx, sy) where it is. A factor > 1 zooms in.
+    ///
+    /// Ignores factors that are not positive and finite.
+    pub fn zoom_at(&mut self, sx: S, sy: S, factor: f64) {
+        if factor <= 0.0 || !factor.is_finite() {
+            return;
+        }
+        let (ax, ay) = self.to_world_f(sx.into() + 0.5, sy.into() + 0.5);
+        let zoom = |r: (f64, f64), a: f64| (a + (r.0 - a) / factor, a + (r.1 - a) / factor);
+        let world_x = zoom(self.world_x, ax);
+        let world_y = zoom(self.world_y, ay);
+        self.set_world(world_x, world_y);
+    }
+
+    /// Move the content by dx, dy cells, as if it was dragged
+    /// with the mouse.
+    pub fn pan(&mut self, dx: i32, dy: i32) {
+        let (scale_x, scale_y) = self.scale();
+        let dx = dx as f64 * scale_x;
+        let dy = if self.flip_y {
+            -(dy as f64 * scale_y)
+        } else {
+            dy as f64 * scale_y
+        };
+        let world_x = (self.world_x.0 - dx, self.world_x.1 - dx);
+        let world_y = (self.world_y.0 - dy, self.world_y.1 - dy);
+        self.set_world(world_x, world_y);
+    }
+}
@@ -0,0 +1,188 @@
+//!
+//! Virtual scrolling for lists with variable item heights.
+//!
+//! [VirtualList] keeps a prefix-sum index (a Fenwick tree) over the
+//! item heights in a slice supplied by the caller. Mapping between a
+//! scroll offset and (item, offset within the item) and changing
+//! a height are O(log n).
+//!
+//! ```rust
+//! use map_range_int::virtual_list::VirtualList;
+//!
+//! let mut storage = [1usize, 3, 1, 2, 1];
+//! let mut list = VirtualList::from_heights(&mut storage);
+//!
+//! assert_eq!(list.total_height(), 8);
+//! assert_eq!(list.offset_of(3), 5);
+//! assert_eq!(list.item_at(2), Some((1, 1)));
+//! assert_eq!(list.item_at(8), None);
+//!
+//! // item 1 got expanded
+//! list.set_height(1, 10);
+//! assert_eq!(list.item_at(11), Some((2, 0)));
+//!
+//! // scrollbar for a page of 5 rows and a track of 5 cells.
+//! let sb = list.scrollbar(5, 5);
+//! assert_eq!(sb.thumb(10), (4, 1));
+//! ```
+//!
+
+use crate::scrollbar::Scrollbar;
+use core::ops::Range;
+
+/// Prefix-sum index over item heights.
+#[derive(Debug)]
+pub struct VirtualList<'a> {
+    tree: &'a mut [usize],
+}
+
+impl<'a> VirtualList<'a> {
+    /// New list with all heights 0. The storage determines the
+    /// number of items.
+    pub fn new(storage: &'a mut [usize]) -> Self {
+        storage.fill(0);
+        Self { tree: storage }
+    }
+
+    /// New list. The storage contains the item heights and is
+    /// converted to the index in place. O(n).
+    pub fn from_heights(storage: &'a mut [usize]) -> Self {
+        let n = storage.len();
+        for i in 0..n {
+            let j = i | (i + 1);
+            if j < n {
+                storage[j] = storage[j].wrapping_add(storage[i]);
+            }
+        }
+        Self { tree: storage }
+    }
+
+    /// Number of items.
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// No items.
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Sum of the heights of the first n items.
+    fn prefix(&self, mut n: usize) -> usize {
+        let mut sum = 0usize;
+        while n > 0 {
+            sum = sum.wrapping_add(self.tree[n - 1]);
+            n &= n - 1;
+        }
+        sum
+    }
+
+    /// Height of an item.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn height(&self, idx: usize) -> usize {
+        assert!(idx < self.tree.len());
+        self.prefix(idx + 1).wrapping_sub(self.prefix(idx))
+    }
+
+    /// Change the height of an item.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn set_height(&mut self, idx: usize, height: usize) {
+        let delta = height.wrapping_sub(self.height(idx));
+        let mut i = idx;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i].wrapping_add(delta);
+            i |= i + 1;
+        }
+    }
+
+    /// Sum of all heights.
+    pub fn total_height(&self) -> usize {
+        self.prefix(self.tree.len())
+    }
+
+    /// Scroll offset where the item starts. Item len() gives
+    /// the total height.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if idx > len().
+    pub fn offset_of(&self, idx: usize) -> usize {
+        assert!(idx <= self.tree.len());
+        self.prefix(idx)
+    }
+
+    /// Item at the scroll offset and the offset within the item.
+    /// Items with height 0 are never hit.
+    ///
+    /// Returns None if the offset is beyond the total height.
+    pub fn item_at(&self, offset: usize) -> Option<(usize, usize)> {
+        let n = self.tree.len();
+        if n == 0 {
+            return None;
+        }
+        // largest pos with prefix(pos) <= offset
+        let mut pos = 0;
+        let mut rest = offset;
+        let mut step = 1 << (usize::BITS - 1 - n.leading_zeros());
+        while step > 0 {
+            if pos + step <= n && self.tree[pos + step - 1] <= rest {
+                pos += step;
+                rest -= self.tree[pos - 1];
+            }
+            step >>= 1;
+        }
+        if pos == n {
+            None
+        } else {
+            Some((pos, rest))
+        }
+    }
+
+    /// Items visible with the scroll offset and the height of
+    /// the page.
+    pub fn visible(&self, offset: usize, page_len: usize) -> Range<usize> {
+        let Some((first, _)) = self.item_at(offset) else {
+            return self.tree.len()..self.tree.len();
+        };
+        if page_len == 0 {
+            return first..first;
+        }
+        let last = match self.item_at(offset.saturating_add(page_len - 1)) {
+            Some((last, _)) => last,
+            None => self.tree.len() - 1,
+        };
+        first..last + 1
+    }
+
+    /// Largest scroll offset for the page height.
+    pub fn max_offset(&self, page_len: usize) -> usize {
+        self.total_height().saturating_sub(page_len)
+    }
+
+    /// Scroll offset that makes the item visible, starting from
+    /// the current offset. Scrolls as little as possible.
+    pub fn scroll_to(&self, idx: usize, offset: usize, page_len: usize) -> usize {
+        let start = self.offset_of(idx);
+        let end = self.offset_of(idx + 1);
+        let offset = if start < offset {
+            start
+        } else if end > offset.saturating_add(page_len) {
+            // show the start if the item is larger than the page.
+            (end - page_len).min(start)
+        } else {
+            offset
+        };
+        offset.min(self.max_offset(page_len))
+    }
+
+    /// Scrollbar for the page height and the track length.
+    pub fn scrollbar(&self, page_len: usize, track_len: usize) -> Scrollbar {
+        Scrollbar::new(self.max_offset(page_len), page_len, track_len)
+    }
+}
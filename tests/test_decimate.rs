@@ -0,0 +1,103 @@
+use map_range_int::decimate::{column_samples, lttb, min_max_columns, Column};
+use map_range_int::MapRange;
+
+#[test]
+fn test_columns() {
+    for n in 0..60usize {
+        for width in 1..25usize {
+            let mut next = 0;
+            for c in 0..width {
+                let r = column_samples(c, n, width);
+                assert_eq!(r.start, next);
+                for i in r.clone() {
+                    assert_eq!(i.map_range((0, n), (0, width)), Some(c));
+                }
+                next = r.end;
+            }
+            assert_eq!(next, n);
+        }
+    }
+    assert_eq!(column_samples(0, 10, 0), 0..0);
+    assert_eq!(column_samples(5, 10, 2), 10..10);
+}
+
+#[test]
+fn test_min_max() {
+    let samples: Vec<i32> = (0..1000).map(|i| (i * 37 % 101) - 50).collect();
+    let cols: Vec<Option<Column<i32>>> = min_max_columns(&samples, 7).collect();
+    assert_eq!(cols.len(), 7);
+    for (c, col) in cols.iter().enumerate() {
+        let s = &samples[column_samples(c, samples.len(), 7)];
+        let col = col.expect("column");
+        assert_eq!(col.min, *s.iter().min().unwrap());
+        assert_eq!(col.max, *s.iter().max().unwrap());
+        assert_eq!(col.first, s[0]);
+        assert_eq!(col.last, s[s.len() - 1]);
+    }
+
+    // more columns than samples
+    let cols: Vec<_> = min_max_columns(&[1u8, 2], 4).collect();
+    assert_eq!(
+        cols,
+        vec![
+            Some(Column {
+                min: 1,
+                max: 1,
+                first: 1,
+                last: 1
+            }),
+            None,
+            Some(Column {
+                min: 2,
+                max: 2,
+                first: 2,
+                last: 2
+            }),
+            None
+        ]
+    );
+    assert_eq!(min_max_columns::<u8>(&[], 3).count(), 3);
+
+    // map into rows
+    let col = Column {
+        min: -1.,
+        max: 0.5,
+        first: 0.,
+        last: 1.,
+    };
+    assert_eq!(
+        col.map_range((-1., 1.), (0u16, 100)),
+        Some(Column {
+            min: 0,
+            max: 75,
+            first: 50,
+            last: 100
+        })
+    );
+    assert_eq!(col.map_range((0., 1.), (0u16, 100)), None);
+}
+
+#[test]
+fn test_lttb() {
+    let samples: Vec<f64> = (0..100).map(|i| if i == 42 { 100. } else { 0. }).collect();
+    let mut idx = [0; 10];
+    assert_eq!(lttb(&samples, &mut idx), 10);
+    assert_eq!(idx[0], 0);
+    assert_eq!(idx[9], 99);
+    // the spike is kept
+    assert!(idx.contains(&42));
+    // sorted, one per bucket
+    assert!(idx.windows(2).all(|w| w[0] < w[1]));
+
+    let mut idx = [0; 2];
+    assert_eq!(lttb(&samples, &mut idx), 2);
+    assert_eq!(idx, [0, 99]);
+
+    let mut idx = [0; 1];
+    assert_eq!(lttb(&samples, &mut idx), 1);
+
+    // fewer samples than output
+    let mut idx = [usize::MAX; 5];
+    assert_eq!(lttb(&[1u8, 2, 3], &mut idx), 3);
+    assert_eq!(idx, [0, 1, 2, usize::MAX, usize::MAX]);
+}
@@ -0,0 +1,142 @@
+use map_range_int::distribute::{distribute, distribute_weighted, index_at, index_at_ends};
+use map_range_int::MapRange;
+
+#[test]
+fn test_equal() {
+    for total in 0u32..50 {
+        for n in 1..12usize {
+            let parts: Vec<u32> = distribute(total, n).collect();
+            assert_eq!(parts.len(), n);
+            assert_eq!(parts.iter().sum::<u32>(), total);
+            let min = *parts.iter().min().unwrap();
+            let max = *parts.iter().max().unwrap();
+            assert!(max - min <= 1);
+
+            // boundaries are the same as map_range()
+            let mut end = 0;
+            for (i, p) in parts.iter().enumerate() {
+                end += p;
+                assert_eq!(Some(end), (i + 1).map_range((0, n), (0, total)));
+            }
+        }
+    }
+
+    assert_eq!(distribute(5u8, 0).count(), 0);
+    assert_eq!(distribute(0u8, 3).collect::<Vec<_>>(), vec![0, 0, 0]);
+    assert_eq!(
+        distribute(u64::MAX, 2).collect::<Vec<_>>(),
+        vec![u64::MAX / 2, u64::MAX / 2 + 1]
+    );
+}
+
+fn largest_remainder(total: u64, weights: &[u64]) -> Vec<u64> {
+    let sum: u128 = weights.iter().map(|v| *v as u128).sum();
+    let t = total as u128;
+    let mut parts: Vec<u64> = weights
+        .iter()
+        .map(|w| (t * *w as u128 / sum) as u64)
+        .collect();
+    let mut idx: Vec<usize> = (0..weights.len()).collect();
+    idx.sort_by_key(|i| (std::cmp::Reverse(t * weights[*i] as u128 % sum), *i));
+    let rest = total - parts.iter().sum::<u64>();
+    for i in idx.iter().take(rest as usize) {
+        parts[*i] += 1;
+    }
+    parts
+}
+
+#[test]
+fn test_weighted() {
+    let cases: &[(u64, &[u64])] = &[
+        (100, &[1, 1, 1]),
+        (10, &[2, 5, 3]),
+        (7, &[1, 2, 3, 4]),
+        (1, &[5, 5, 5, 5]),
+        (0, &[1, 2]),
+        (1000, &[333, 333, 334]),
+        (17, &[0, 3, 0, 7, 1]),
+        (u64::MAX, &[u64::MAX, 1, u64::MAX]),
+        (99, &[7]),
+    ];
+    for (total, weights) in cases {
+        let parts: Vec<u64> = distribute_weighted(*total, weights).collect();
+        assert_eq!(parts, largest_remainder(*total, weights));
+        assert_eq!(
+            parts.iter().map(|v| *v as u128).sum::<u128>(),
+            *total as u128
+        );
+    }
+
+    // pseudo random
+    let mut seed = 0x2545f491u32;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed
+    };
+    for _ in 0..200 {
+        let n = (next() % 9 + 1) as usize;
+        let weights: Vec<u64> = (0..n).map(|_| (next() % 20) as u64 + 1).collect();
+        let total = (next() % 500) as u64;
+        let parts: Vec<u64> = distribute_weighted(total, &weights).collect();
+        assert_eq!(parts, largest_remainder(total, &weights));
+    }
+}
+
+#[test]
+fn test_weighted_edge() {
+    // all zero weights split equally
+    assert_eq!(
+        distribute_weighted(10u8, &[0, 0, 0]).collect::<Vec<_>>(),
+        vec![3, 3, 4]
+    );
+    assert_eq!(distribute_weighted(10u8, &[]).count(), 0);
+    // u8 with a large weight sum
+    let weights = [255u8; 300];
+    let parts: Vec<u8> = distribute_weighted(255u8, &weights).collect();
+    assert_eq!(parts.iter().map(|v| *v as u32).sum::<u32>(), 255);
+    assert_eq!(parts[0], 1);
+    assert_eq!(parts[299], 0);
+    assert_eq!(distribute_weighted(5u16, &[1, 2]).len(), 2);
+}
+
+#[test]
+fn test_index_at() {
+    for extent in 0u16..40 {
+        for n in 1..12usize {
+            let mut pos = 0;
+            for (i, len) in distribute(extent, n).enumerate() {
+                for off in 0..len {
+                    assert_eq!(index_at(pos, n, extent), Some((i, off)));
+                    pos += 1;
+                }
+            }
+            assert_eq!(index_at(extent, n, extent), None);
+        }
+    }
+    assert_eq!(index_at(3u8, 0, 10), None);
+    assert_eq!(
+        index_at(u64::MAX - 1, 3, u64::MAX),
+        Some((2, u64::MAX / 3 - 1))
+    );
+}
+
+#[test]
+fn test_index_at_ends() {
+    let weights = [3u32, 0, 5, 1, 0];
+    let mut ends = [0u32; 5];
+    let mut sum = 0;
+    for (e, v) in ends.iter_mut().zip(distribute_weighted(20, &weights)) {
+        sum += v;
+        *e = sum;
+    }
+    assert_eq!(ends, [7, 7, 18, 20, 20]);
+
+    assert_eq!(index_at_ends(0, &ends), Some((0, 0)));
+    assert_eq!(index_at_ends(6, &ends), Some((0, 6)));
+    assert_eq!(index_at_ends(7, &ends), Some((2, 0)));
+    assert_eq!(index_at_ends(19, &ends), Some((3, 1)));
+    assert_eq!(index_at_ends(20, &ends), None);
+    assert_eq!(index_at_ends(0u8, &[]), None);
+}
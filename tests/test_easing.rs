@@ -0,0 +1,109 @@
+use map_range_int::easing::{Ease, MapRangeEased};
+
+fn all() -> [Ease; 19] {
+    [
+        Ease::Linear,
+        Ease::QuadIn,
+        Ease::QuadOut,
+        Ease::QuadInOut,
+        Ease::CubicIn,
+        Ease::CubicOut,
+        Ease::CubicInOut,
+        Ease::SmoothStep,
+        Ease::SmootherStep,
+        Ease::BackIn,
+        Ease::BackOut,
+        Ease::BackInOut,
+        Ease::ElasticIn,
+        Ease::ElasticOut,
+        Ease::ElasticInOut,
+        Ease::BounceIn,
+        Ease::BounceOut,
+        Ease::BounceInOut,
+        Ease::cubic_bezier(0.25, 0.1, 0.25, 1.0),
+    ]
+}
+
+#[test]
+fn test_endpoints() {
+    for ease in all() {
+        assert_eq!(ease.apply(0.), 0., "{:?}", ease);
+        assert_eq!(ease.apply(1.), 1., "{:?}", ease);
+        assert_eq!(ease.apply_q16(0), 0, "{:?}", ease);
+        assert_eq!(ease.apply_q16(65536), 65536, "{:?}", ease);
+
+        let r: u8 = 0u16
+            .map_range_eased((0, 1000), (10, 250), ease)
+            .expect("in_range");
+        assert_eq!(r, 10);
+        let r: u8 = 1000u16
+            .map_range_eased((0, 1000), (10, 250), ease)
+            .expect("in_range");
+        assert_eq!(r, 250);
+        let r: f64 = 1000u16
+            .map_range_eased((0, 1000), (0.1, 0.7), ease)
+            .expect("in_range");
+        assert_eq!(r, 0.7);
+        let r: Option<u8> = 1001u16.map_range_eased((0, 1000), (10, 250), ease);
+        assert_eq!(r, None);
+    }
+}
+
+#[test]
+fn test_fixed_float() {
+    // the fixed-point variant stays close to the float one.
+    for ease in all() {
+        for t in (0..=65536).step_by(97) {
+            let f = ease.apply(t as f64 / 65536.);
+            let q = ease.apply_q16(t) as f64 / 65536.;
+            assert!((f - q).abs() < 2e-4, "{:?} {} {} {}", ease, t, f, q);
+        }
+    }
+}
+
+#[test]
+fn test_values() {
+    assert_eq!(Ease::QuadIn.apply(0.5), 0.25);
+    assert_eq!(Ease::QuadOut.apply(0.5), 0.75);
+    assert_eq!(Ease::CubicInOut.apply(0.5), 0.5);
+    assert_eq!(Ease::SmoothStep.apply(0.5), 0.5);
+    assert_eq!(Ease::SmootherStep.apply(0.5), 0.5);
+    assert_eq!(Ease::BounceOut.apply(0.5), 0.765625);
+    assert!((Ease::BackIn.apply(0.5) - -0.0876975).abs() < 1e-6);
+    assert!((Ease::ElasticOut.apply(0.5) - 1.015625).abs() < 1e-6);
+    // linear bezier
+    assert!((Ease::cubic_bezier(0., 0., 1., 1.).apply(0.3) - 0.3).abs() < 1e-4);
+
+    assert_eq!(Ease::QuadIn.apply_q16(32768), 16384);
+    assert_eq!(Ease::BounceOut.apply_q16(32768), 50176);
+}
+
+#[test]
+fn test_overshoot() {
+    // back overshoots below the start, saturates for unsigned.
+    let r: u8 = 100u16
+        .map_range_eased((0, 1000), (0, 100), Ease::BackIn)
+        .expect("in_range");
+    assert_eq!(r, 0);
+    let r: i8 = 100u16
+        .map_range_eased((0, 1000), (0, 100), Ease::BackIn)
+        .expect("in_range");
+    assert_eq!(r, -2);
+    let r: f64 = 100u16
+        .map_range_eased((0, 1000), (0., 100.), Ease::BackIn)
+        .expect("in_range");
+    assert!((r - -1.4314).abs() < 1e-3);
+}
+
+#[test]
+fn test_reversed() {
+    let mut last = 255;
+    for t in 0..=100u32 {
+        let r: u8 = t
+            .map_range_eased((0, 100), (255, 0), Ease::CubicInOut)
+            .expect("in_range");
+        assert!(r <= last);
+        last = r;
+    }
+    assert_eq!(last, 0);
+}
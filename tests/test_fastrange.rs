@@ -0,0 +1,64 @@
+use map_range_int::fastrange::{fastrange32, fastrange64, fastrange_slice, fastrange_usize};
+use map_range_int::MapRange;
+
+#[test]
+fn test_same_as_map_range() {
+    // map_range with a source span of 2^32.
+    for n in [1u32, 2, 3, 7, 10, 1000, u32::MAX] {
+        for h in [0u32, 1, 2, 12345, 1 << 31, u32::MAX - 1, u32::MAX] {
+            let m: u64 = (h as u64).map_range_unchecked((0, 1 << 32), (0, n as u64));
+            assert_eq!(fastrange32(h, n) as u64, m);
+            assert!(fastrange32(h, n) < n);
+        }
+    }
+    assert_eq!(fastrange32(u32::MAX, 0), 0);
+    assert_eq!(fastrange64(u64::MAX, 0), 0);
+}
+
+#[test]
+fn test_buckets() {
+    // bucket k starts at ceil(k * 2^32 / n).
+    for n in [3u32, 7, 10, 641] {
+        let mut sizes = Vec::new();
+        let mut start = 0u64;
+        for k in 1..=n as u64 {
+            let end = (k << 32).div_ceil(n as u64);
+            assert_eq!(fastrange32(start as u32, n) as u64, k - 1);
+            assert_eq!(fastrange32((end - 1) as u32, n) as u64, k - 1);
+            sizes.push(end - start);
+            start = end;
+        }
+        assert_eq!(start, 1 << 32);
+        let min = sizes.iter().min().unwrap();
+        let max = sizes.iter().max().unwrap();
+        assert!(max - min <= 1);
+    }
+
+    for n in [3u64, 10, u64::MAX] {
+        assert_eq!(fastrange64(0, n), 0);
+        assert_eq!(fastrange64(u64::MAX, n), n - 1);
+        let start = ((n as u128 - 1) << 64).div_ceil(n as u128) as u64;
+        assert_eq!(fastrange64(start, n), n - 1);
+        assert_eq!(fastrange64(start - 1, n), n - 2);
+    }
+}
+
+#[test]
+fn test_usize() {
+    assert_eq!(fastrange_usize(0, 5), 0);
+    assert_eq!(fastrange_usize(usize::MAX, 5), 4);
+    assert_eq!(fastrange_usize(usize::MAX / 2 + 1, 6), 3);
+    assert_eq!(fastrange_usize(usize::MAX, usize::MAX), usize::MAX - 1);
+}
+
+#[test]
+fn test_slice() {
+    let hashes = [0, u64::MAX, 1 << 63, (1 << 63) - 1];
+    let mut buckets = [99; 5];
+    fastrange_slice(&hashes, 8, &mut buckets);
+    assert_eq!(buckets, [0, 7, 4, 3, 99]);
+
+    let mut buckets = [99; 2];
+    fastrange_slice(&hashes, 8, &mut buckets);
+    assert_eq!(buckets, [0, 7]);
+}
@@ -0,0 +1,111 @@
+use map_range_int::gamma::{
+    linear16_to_rec709_8, linear16_to_srgb8, linear_to_rec709, linear_to_srgb,
+    rec709_8_to_linear16, rec709_to_linear, srgb8_to_linear16, srgb_to_linear, Curve,
+    MapRangeCurve,
+};
+
+#[test]
+fn test_pow() {
+    let r: u8 = 0u8.map_range_pow((0, 255), (10, 20), 2.).expect("in_range");
+    assert_eq!(r, 10);
+    let r: u8 = 255u8
+        .map_range_pow((0, 255), (10, 20), 2.)
+        .expect("in_range");
+    assert_eq!(r, 20);
+    let r: f64 = 5u8
+        .map_range_pow((0, 10), (0., 100.), 2.)
+        .expect("in_range");
+    assert!((r - 25.).abs() < 1e-12);
+    let r: f64 = 25u8.map_range_sqrt((0, 100), (0., 10.)).expect("in_range");
+    assert!((r - 5.).abs() < 1e-12);
+    let r: f64 = 0.25f64
+        .map_range_gamma((0., 1.), (0., 1.), 2.)
+        .expect("in_range");
+    assert!((r - 0.5).abs() < 1e-12);
+
+    let r: Option<u8> = 11u8.map_range_pow((0, 10), (0, 100), 2.);
+    assert_eq!(r, None);
+    let r: Option<u8> = 5u8.map_range_pow((0, 10), (100, 0), 2.);
+    assert_eq!(r, None);
+
+    // invalid exponent or gamma.
+    let r: Option<u8> = 5u8.map_range_pow((0, 10), (100, 200), -1.);
+    assert_eq!(r, None);
+    let r: Option<u8> = 5u8.map_range_pow((0, 10), (100, 200), f64::NAN);
+    assert_eq!(r, None);
+    let r: Option<u8> = 5u8.map_range_curve((0, 10), (100, 200), Curve::Gamma(-2.));
+    assert_eq!(r, None);
+    let r: Option<u8> = 5u8.map_range_gamma((0, 10), (100, 200), 0.);
+    assert_eq!(r, None);
+
+    // monotone with exact endpoints for integer targets.
+    let mut last = 0u16;
+    for x in 0..=255u8 {
+        let r: u16 = x
+            .map_range_gamma((0, 255), (0, 65535), 2.2)
+            .expect("in_range");
+        assert!(r >= last);
+        last = r;
+    }
+    assert_eq!(last, 65535);
+}
+
+#[test]
+fn test_transfer() {
+    for f in [
+        srgb_to_linear,
+        linear_to_srgb,
+        rec709_to_linear,
+        linear_to_rec709,
+    ] {
+        assert_eq!(f(0.), 0.);
+        assert_eq!(f(1.), 1.);
+    }
+    assert!((srgb_to_linear(0.5) - 0.21404114).abs() < 1e-8);
+    assert!((linear_to_srgb(0.21404114) - 0.5).abs() < 1e-8);
+    assert!((srgb_to_linear(0.04) - 0.04 / 12.92).abs() < 1e-15);
+    assert!((linear_to_rec709(0.01) - 0.045).abs() < 1e-15);
+    assert!((rec709_to_linear(linear_to_rec709(0.5)) - 0.5).abs() < 1e-12);
+
+    let r: f32 = 1u8
+        .map_range_curve((0, 1), (0., 1.), Curve::LinearToSrgb)
+        .expect("in_range");
+    assert_eq!(r, 1.);
+}
+
+#[test]
+fn test_srgb_lut() {
+    assert_eq!(srgb8_to_linear16(0), 0);
+    assert_eq!(srgb8_to_linear16(255), 65535);
+    assert_eq!(linear16_to_srgb8(0), 0);
+    assert_eq!(linear16_to_srgb8(65535), 255);
+
+    for v in 0..=255u8 {
+        let l = srgb8_to_linear16(v);
+        let f: f64 = srgb_to_linear(v as f64 / 255.) * 65535.;
+        assert!((l as f64 - f).abs() <= 0.5 + 1e-9);
+        assert_eq!(linear16_to_srgb8(l), v);
+    }
+
+    let mut last = 0;
+    for l in 0..=65535u16 {
+        let v = linear16_to_srgb8(l);
+        assert!(v >= last);
+        let f: f64 = linear_to_srgb(l as f64 / 65535.) * 255.;
+        assert!((v as f64 - f).abs() <= 0.5 + 1e-9);
+        last = v;
+    }
+}
+
+#[test]
+fn test_rec709_lut() {
+    assert_eq!(rec709_8_to_linear16(0), 0);
+    assert_eq!(rec709_8_to_linear16(255), 65535);
+    assert_eq!(linear16_to_rec709_8(0), 0);
+    assert_eq!(linear16_to_rec709_8(65535), 255);
+
+    for v in 0..=255u8 {
+        let l = rec709_8_to_linear16(v);
+        assert_eq!(linear16_to_rec709_8(l), v);
+    }
+}
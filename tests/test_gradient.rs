@@ -0,0 +1,67 @@
+use map_range_int::gradient::{
+    Gradient, MapRangeGradient, Rgba8, GRAYSCALE, INFERNO, MAGMA, TURBO, VIRIDIS,
+};
+
+#[test]
+fn test_new() {
+    assert!(Gradient::new(&[]).is_none());
+    assert!(Gradient::new(&[(10, Rgba8::rgb8(0, 0, 0)), (5, Rgba8::rgb8(0, 0, 0))]).is_none());
+    assert!(Gradient::new(&[(10, Rgba8::rgb8(0, 0, 0))]).is_some());
+}
+
+#[test]
+fn test_stops() {
+    let stops = [
+        (1000, Rgba8::new(10, 20, 30, 0)),
+        (2000, Rgba8::new(110, 20, 0, 255)),
+        (2000, Rgba8::new(0, 0, 0, 255)),
+        (3000, Rgba8::new(255, 255, 255, 255)),
+    ];
+    let g = Gradient::new(&stops).expect("stops");
+
+    // constant outside
+    assert_eq!(g.at(0), stops[0].1);
+    assert_eq!(g.at(1000), stops[0].1);
+    assert_eq!(g.at(65535), stops[3].1);
+
+    // hard edge
+    assert_eq!(g.at(1999), Rgba8::new(110, 20, 0, 255));
+    assert_eq!(g.at(2000), Rgba8::new(0, 0, 0, 255));
+
+    assert_eq!(g.at(1500), Rgba8::new(60, 20, 15, 128));
+    assert_eq!(g.at(2500), Rgba8::new(128, 128, 128, 255));
+}
+
+#[test]
+fn test_linear_light() {
+    let g = GRAYSCALE.linear_light(true);
+    assert_eq!(g.at(0).rgb(), [0, 0, 0]);
+    assert_eq!(g.at(65535).rgb(), [255, 255, 255]);
+    // half the light is brighter than half the sRGB value.
+    assert_eq!(g.at(32768).rgb(), [188, 188, 188]);
+    assert_eq!(GRAYSCALE.at(32768).rgb(), [128, 128, 128]);
+}
+
+#[test]
+fn test_map() {
+    for g in [GRAYSCALE, VIRIDIS, MAGMA, INFERNO, TURBO] {
+        let first = g.stops()[0].1;
+        let last = g.stops()[g.stops().len() - 1].1;
+
+        assert_eq!(0u8.map_range_gradient((0, 255), &g), Some(first));
+        assert_eq!(255u8.map_range_gradient((0, 255), &g), Some(last));
+        assert_eq!((-5i32).map_range_gradient((-5, 5), &g), Some(first));
+        assert_eq!(1.0f64.map_range_gradient((0., 1.), &g), Some(last));
+        assert_eq!(1.5f64.map_range_gradient((0., 1.), &g), None);
+    }
+
+    assert_eq!(MAGMA.at(0).rgb(), [0, 0, 4]);
+    assert_eq!(INFERNO.at(65535).rgb(), [252, 255, 164]);
+    assert_eq!(TURBO.at(0).rgb(), [48, 18, 59]);
+
+    // spot check against the reference table
+    let c = 128u8
+        .map_range_gradient((0, 255), &VIRIDIS)
+        .expect("in_range");
+    assert!(c.r.abs_diff(33) <= 3 && c.g.abs_diff(145) <= 3 && c.b.abs_diff(140) <= 3);
+}
@@ -0,0 +1,90 @@
+use map_range_int::histogram::{Bin, Histogram};
+
+#[test]
+fn test_counts() {
+    let mut bins = [7u64; 3];
+    let mut h = Histogram::new((-10i8, 20), &mut bins).expect("histogram");
+    assert_eq!(h.bins(), &[0, 0, 0]);
+
+    h.extend(&[-128, -11, -10, -1, 0, 9, 10, 19, 20, 21, 127]);
+    assert_eq!(h.underflow(), 2);
+    assert_eq!(h.overflow(), 2);
+    assert_eq!(h.bins(), &[2, 2, 3]);
+    assert_eq!(h.total(), 11);
+
+    h.clear();
+    assert_eq!(h.total(), 0);
+
+    assert!(Histogram::new((5u8, 4), &mut [0; 3]).is_none());
+    assert!(Histogram::new((0u8, 4), &mut []).is_none());
+}
+
+#[test]
+fn test_edges_int() {
+    for n in 1..20usize {
+        let mut bins = vec![0u64; n];
+        let h = Histogram::new((-7i16, 11), &mut bins).expect("histogram");
+        for i in 0..n {
+            let (start, end) = h.bin_range(i).expect("bin");
+            for v in start..end {
+                assert_eq!(h.bin(v), Bin::Bin(i));
+            }
+            if i + 1 == n {
+                assert_eq!(end, 11);
+                assert_eq!(h.bin(end), Bin::Bin(i));
+            }
+        }
+        assert_eq!(h.bin_range(0).expect("bin").0, -7);
+        assert_eq!(h.bin_range(n), None);
+    }
+
+    let mut bins = [0u64; 4];
+    let h = Histogram::new((0u64, u64::MAX), &mut bins).expect("histogram");
+    assert_eq!(h.bin_range(1), Some((1 << 62, 1 << 63)));
+    assert_eq!(h.bin((1 << 62) - 1), Bin::Bin(0));
+    assert_eq!(h.bin(u64::MAX), Bin::Bin(3));
+}
+
+#[test]
+fn test_edges_float() {
+    for n in 1..30usize {
+        let mut bins = vec![0u64; n];
+        let h = Histogram::new((-0.3f64, 0.7), &mut bins).expect("histogram");
+        for i in 0..n {
+            let (start, end) = h.bin_range(i).expect("bin");
+            assert_eq!(h.bin(start), Bin::Bin(i));
+            if i + 1 < n {
+                assert_eq!(h.bin(end), Bin::Bin(i + 1));
+                assert_eq!(h.bin(end.next_down()), Bin::Bin(i));
+            } else {
+                assert_eq!(end, 0.7);
+                assert_eq!(h.bin(end), Bin::Bin(i));
+            }
+        }
+    }
+
+    let mut bins = [0u64; 10];
+    let h = Histogram::new((0f32, 1.), &mut bins).expect("histogram");
+    let (start, _) = h.bin_range(3).expect("bin");
+    assert_eq!(h.bin(start), Bin::Bin(3));
+    assert_eq!(h.bin(start.next_down()), Bin::Bin(2));
+}
+
+#[test]
+fn test_nan() {
+    let mut bins = [0u64; 2];
+    let mut h = Histogram::new((0f64, 1.), &mut bins).expect("histogram");
+    h.extend(&[f64::NAN, -0.5, 0.5, 1.5]);
+    assert_eq!(h.bins(), &[0, 1]);
+    assert_eq!(h.underflow(), 1);
+    assert_eq!(h.overflow(), 2);
+}
+
+#[test]
+fn test_empty_range() {
+    let mut bins = [0u64; 3];
+    let mut h = Histogram::new((4u32, 4), &mut bins).expect("histogram");
+    h.extend(&[3, 4, 5]);
+    assert_eq!(h.bins(), &[1, 0, 0]);
+    assert_eq!(h.bin_range(2), Some((4, 4)));
+}
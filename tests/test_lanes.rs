@@ -0,0 +1,65 @@
+use map_range_int::lanes::{LaneError, MapRangeLanes, MapRangeShared};
+
+#[test]
+fn test_array() {
+    let r: [u16; 4] = [0u8, 10, 20, 255]
+        .map_range_shared((0, 255), (0, 65535))
+        .expect("in_range");
+    assert_eq!(r, [0, 2570, 5140, 65535]);
+
+    let r: Result<[u16; 3], _> = [0u8, 10, 20].map_range_shared((0, 15), (0, 65535));
+    assert_eq!(r, Err(LaneError::OutOfBounds(2)));
+    let r: Result<[u16; 3], _> = [0u8, 10, 20].map_range_shared((0, 255), (100, 0));
+    assert_eq!(r, Err(LaneError::ReversedSharedRange));
+
+    let r: [i16; 2] = [-1.0f64, 1.0]
+        .map_range_lanes([(-1., 1.), (0., 1.)], [(-100, 100), (0, 10)])
+        .expect("in_range");
+    assert_eq!(r, [-100, 10]);
+
+    let r: Result<[i16; 2], _> =
+        [0.0f64, 2.0].map_range_lanes([(-1., 1.), (0., 1.)], [(-100, 100), (0, 10)]);
+    assert_eq!(r, Err(LaneError::OutOfBounds(1)));
+    let r: Result<[i16; 2], _> =
+        [0.0f64, 2.0].map_range_lanes([(-1., 1.), (0., 1.)], [(100, -100), (0, 10)]);
+    assert_eq!(r, Err(LaneError::ReversedRange(0)));
+}
+
+#[test]
+fn test_tuple() {
+    let r: (u8, i16) = (5u8, 0.5f32)
+        .map_range_lanes(((0, 10), (0., 1.)), ((0, 100), (-100, 100)))
+        .expect("in_range");
+    assert_eq!(r, (50, 0));
+
+    let r: (u8, u8, u8) = (0u16, 32768u16, 65535u16)
+        .map_range_shared((0, 65535), (0, 255))
+        .expect("in_range");
+    assert_eq!(r, (0, 127, 255));
+
+    let r: Result<(u8, u8, u8, u8), _> =
+        (0u16, 1u16, 2u16, 3u16).map_range_shared((0, 2), (0, 255));
+    assert_eq!(r, Err(LaneError::OutOfBounds(3)));
+    let r: Result<(u8, u8), _> = (1u16, 2u16).map_range_shared((0, 2), (255, 0));
+    assert_eq!(r, Err(LaneError::ReversedSharedRange));
+
+    let r: Result<(u8, u8, u8), _> = (1u8, 2u8, 3u8)
+        .map_range_lanes(((0, 10), (0, 10), (0, 10)), ((0, 100), (0, 100), (100, 0)));
+    assert_eq!(r, Err(LaneError::ReversedRange(2)));
+}
+
+#[test]
+fn test_same_as_map_range() {
+    use map_range_int::MapRange;
+
+    for x in 0..=255u8 {
+        let lanes: [i16; 2] = [x, 255 - x]
+            .map_range_shared((0, 255), (-1000, 1000))
+            .expect("in_range");
+        let a: i16 = x.map_range((0, 255), (-1000, 1000)).expect("in_range");
+        let b: i16 = (255 - x)
+            .map_range((0, 255), (-1000, 1000))
+            .expect("in_range");
+        assert_eq!(lanes, [a, b]);
+    }
+}
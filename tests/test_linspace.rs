@@ -0,0 +1,50 @@
+use map_range_int::linspace::linspace;
+use map_range_int::MapRange;
+
+macro_rules! check {
+    ($ty:ty, $range:expr) => {
+        for n in 0..40usize {
+            let v: Vec<$ty> = linspace($range, n).expect("range").collect();
+            assert_eq!(v.len(), n);
+            for (i, x) in v.iter().enumerate() {
+                assert_eq!(Some(*x), i.map_range((0, n - 1), $range));
+            }
+            if n > 0 {
+                assert_eq!(v[0], $range.0);
+            }
+            if n > 1 {
+                assert_eq!(v[n - 1], $range.1);
+            }
+        }
+    };
+}
+
+#[test]
+fn test_same_as_map_range() {
+    check!(u8, (0u8, 255u8));
+    check!(u8, (7u8, 11u8));
+    check!(u8, (9u8, 9u8));
+    check!(i8, (-128i8, 127i8));
+    check!(i16, (-300i16, -17i16));
+    check!(u32, (0u32, u32::MAX));
+    check!(i32, (i32::MIN, i32::MAX));
+    check!(u64, (0u64, u64::MAX));
+    check!(i64, (i64::MIN, i64::MAX));
+    check!(usize, (3usize, 1000usize));
+    check!(isize, (-5isize, 5isize));
+    check!(f32, (-1f32, 1f32));
+    check!(f64, (0.1f64, 0.7f64));
+}
+
+#[test]
+fn test_edge() {
+    assert!(linspace((5u8, 4), 3).is_none());
+    assert!(linspace((1., 0.), 3).is_none());
+    assert_eq!(linspace((5u8, 9), 1).unwrap().collect::<Vec<_>>(), vec![5]);
+    assert_eq!(linspace((5u8, 9), 0).unwrap().count(), 0);
+
+    let mut it = linspace((0u16, 10), 3).unwrap();
+    assert_eq!(it.len(), 3);
+    it.next();
+    assert_eq!(it.len(), 2);
+}
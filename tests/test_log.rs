@@ -0,0 +1,124 @@
+use map_range_int::log::{Decibel, MapRangeExp, MapRangeLog, MapRangeSymExp, MapRangeSymLog};
+
+#[test]
+fn test_exp_endpoints() {
+    let r: f64 = 0u8
+        .map_range_exp((0, 127), (20., 20000.))
+        .expect("in_range");
+    assert_eq!(r, 20.);
+    let r: f64 = 127u8
+        .map_range_exp((0, 127), (20., 20000.))
+        .expect("in_range");
+    assert_eq!(r, 20000.);
+    let r: f32 = 127u8
+        .map_range_exp((0, 127), (20., 20000.))
+        .expect("in_range");
+    assert_eq!(r, 20000.);
+
+    let r: u32 = 0u8.map_range_exp((0, 127), (20, 20000)).expect("in_range");
+    assert_eq!(r, 20);
+    let r: u32 = 127u8
+        .map_range_exp((0, 127), (20, 20000))
+        .expect("in_range");
+    assert_eq!(r, 20000);
+
+    let r: Option<f64> = 128u8.map_range_exp((0, 127), (20., 20000.));
+    assert_eq!(r, None);
+    let r: Option<f64> = 10u8.map_range_exp((0, 127), (0., 20000.));
+    assert_eq!(r, None);
+    let r: Option<u32> = 10u8.map_range_exp((0, 127), (20000, 20));
+    assert_eq!(r, None);
+}
+
+#[test]
+fn test_exp_int_float() {
+    // the fixed-point variant stays close to the float result.
+    for x in 0..=255u8 {
+        let i: u32 = x.map_range_exp((0, 255), (1, 1_000_000)).expect("in_range");
+        let f: f64 = x
+            .map_range_exp((0, 255), (1., 1_000_000.))
+            .expect("in_range");
+        assert!((i as f64 - f).abs() <= 0.5 + f * 1e-6, "{} {} {}", x, i, f);
+    }
+
+    let r: u32 = 64u8.map_range_exp((0, 127), (20, 20000)).expect("in_range");
+    assert_eq!(r, 650);
+}
+
+#[test]
+fn test_log_inverse() {
+    for x in 0..=127u8 {
+        let hz: f64 = x.map_range_exp((0, 127), (20., 20000.)).expect("in_range");
+        let back: f64 = hz
+            .map_range_log((20., 20000.), (0., 127.))
+            .expect("in_range");
+        assert!((back - x as f64).abs() < 1e-9);
+    }
+
+    for x in 0..=127u8 {
+        let hz: u64 = x.map_range_exp((0, 127), (20, 20000)).expect("in_range");
+        let back: u8 = hz.map_range_log((20, 20000), (0, 127)).expect("in_range");
+        // integer rounding of hz loses some resolution at the low end.
+        assert!(back.abs_diff(x) <= 1, "{} {} {}", x, hz, back);
+    }
+
+    let r: u8 = 20u16
+        .map_range_log((20, 20000), (0, 127))
+        .expect("in_range");
+    assert_eq!(r, 0);
+    let r: u8 = 20000u16
+        .map_range_log((20, 20000), (0, 127))
+        .expect("in_range");
+    assert_eq!(r, 127);
+    let r: Option<u8> = 0u16.map_range_log((0, 20000), (0, 127));
+    assert_eq!(r, None);
+    let r: Option<u8> = (-1f64).map_range_log((-2., 20000.), (0, 127));
+    assert_eq!(r, None);
+}
+
+#[test]
+fn test_symlog() {
+    let r: f64 = 0.5f64
+        .map_range_symexp((0., 1.), (-1000., 1000.), 1.)
+        .expect("in_range");
+    assert_eq!(r, 0.);
+    let r: f64 = 1f64
+        .map_range_symexp((0., 1.), (-1000., 1000.), 1.)
+        .expect("in_range");
+    assert_eq!(r, 1000.);
+    let r: f64 = 0f64
+        .map_range_symexp((0., 1.), (-1000., 1000.), 1.)
+        .expect("in_range");
+    assert_eq!(r, -1000.);
+
+    let r: u16 = 0f64
+        .map_range_symlog((-1000., 1000.), (0, 800), 1.)
+        .expect("in_range");
+    assert_eq!(r, 400);
+    let r: u16 = (-1000f64)
+        .map_range_symlog((-1000., 1000.), (0, 800), 1.)
+        .expect("in_range");
+    assert_eq!(r, 0);
+
+    for x in -100..=100i32 {
+        let v: f64 = x
+            .map_range_symexp((-100, 100), (-1e6, 1e6), 10.)
+            .expect("in_range");
+        let back: f64 = v
+            .map_range_symlog((-1e6, 1e6), (-100., 100.), 10.)
+            .expect("in_range");
+        assert!((back - x as f64).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_decibel() {
+    assert_eq!(0f64.db_to_gain(), 1.);
+    assert_eq!(1f64.gain_to_db(), 0.);
+    assert!(((-6f64).db_to_gain() - 0.501187233627).abs() < 1e-9);
+    assert!((0.5f64.gain_to_db() - -6.020599913279).abs() < 1e-9);
+    assert!((20f64.db_to_gain() - 10.).abs() < 1e-12);
+    assert!((10f64.db_to_power() - 10.).abs() < 1e-12);
+    assert!((100f32.power_to_db() - 20.).abs() < 1e-5);
+    assert_eq!(0f64.gain_to_db(), f64::NEG_INFINITY);
+}
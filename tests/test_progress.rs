@@ -0,0 +1,68 @@
+use map_range_int::progress::{MapRangeProgress, Progress, HBLOCKS};
+
+#[test]
+fn test_endpoints() {
+    assert_eq!(
+        0u8.map_range_progress((0, 255), 10),
+        Some(Progress {
+            full: 0,
+            partial: 0,
+            empty: 10
+        })
+    );
+    assert_eq!(
+        255u8.map_range_progress((0, 255), 10),
+        Some(Progress {
+            full: 10,
+            partial: 0,
+            empty: 0
+        })
+    );
+    assert_eq!(
+        254u8.map_range_progress((0, 255), 10).unwrap().eighths(),
+        79
+    );
+
+    // float max is exactly full
+    for max in [0.3f64, 0.7, 1.1, 3.3, 0.1000001, 12345.678] {
+        let p = max.map_range_progress((0.1, max), 7).expect("in_range");
+        assert_eq!(p.full, 7);
+    }
+    let p = 0.1f32.map_range_progress((0.1, 0.7), 7).expect("in_range");
+    assert_eq!(p.eighths(), 0);
+
+    assert_eq!(
+        (-5i32).map_range_progress((-5, 5), 0),
+        Some(Progress::default())
+    );
+}
+
+#[test]
+fn test_oob() {
+    assert_eq!(101u8.map_range_progress((0, 100), 10), None);
+    assert_eq!((-1i64).map_range_progress((0, 100), 10), None);
+}
+
+#[test]
+fn test_monotone() {
+    let mut last = 0;
+    for v in 0u16..=1000 {
+        let p = v.map_range_progress((0, 1000), 13).expect("in_range");
+        assert_eq!(p.full + (p.partial > 0) as usize + p.empty, 13);
+        assert!(p.eighths() >= last);
+        last = p.eighths();
+    }
+    assert_eq!(last, 13 * 8);
+}
+
+#[test]
+fn test_glyphs() {
+    let p = Progress::from_eighths(19, 4);
+    assert_eq!(p.glyphs().collect::<Vec<_>>(), vec![8, 8, 3, 0]);
+    let bar: String = p.glyphs().map(|v| HBLOCKS[v as usize]).collect();
+    assert_eq!(bar, "██▍ ");
+
+    // clamped
+    let p = Progress::from_eighths(100, 2);
+    assert_eq!(p.glyphs().collect::<Vec<_>>(), vec![8, 8]);
+}
@@ -0,0 +1,73 @@
+use map_range_int::rate::RateConverter;
+
+#[test]
+fn test_cumulative() {
+    let mut seed = 0x9e3779b9u32;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed
+    };
+    for (from, to) in [
+        (48000, 44100),
+        (44100, 48000),
+        (32768, 1000),
+        (3, 7),
+        (1, 1),
+        (7, 0),
+    ] {
+        let mut conv = RateConverter::new(from, to).expect("rate");
+        let mut sum_in = 0u64;
+        let mut sum_out = 0u64;
+        for _ in 0..2000 {
+            let ticks = (next() % 1000) as u64;
+            sum_in += ticks;
+            sum_out += conv.convert(ticks).expect("fits");
+            assert_eq!(sum_out as u128, sum_in as u128 * to as u128 / from as u128);
+        }
+    }
+}
+
+#[test]
+fn test_signed() {
+    let mut conv = RateConverter::new(2000, 5000).expect("rate");
+    assert_eq!(conv.rates(), (2, 5));
+    let mut sum_in = 0i64;
+    let mut sum_out = 0i64;
+    for ticks in [3, -3, -1, -1, 5, 7, -100, 1, 1, 1] {
+        sum_in += ticks;
+        sum_out += conv.convert_signed(ticks).expect("fits");
+        assert_eq!(sum_out, (sum_in * 5).div_euclid(2));
+    }
+}
+
+#[test]
+fn test_edge() {
+    assert_eq!(RateConverter::new(0, 10), None);
+
+    let mut conv = RateConverter::new(u64::MAX, u64::MAX - 1).expect("rate");
+    assert_eq!(conv.convert(u64::MAX), Some(u64::MAX - 1));
+    assert_eq!(conv.remainder(), 0);
+
+    let mut conv = RateConverter::new(3, 1).expect("rate");
+    assert_eq!(conv.convert(2), Some(0));
+    assert_eq!(conv.remainder(), 2);
+    assert_eq!(conv.convert(1), Some(1));
+    conv.convert(2);
+    conv.reset();
+    assert_eq!(conv.convert(2), Some(0));
+
+    let mut conv = RateConverter::new(u64::MAX, 1).expect("rate");
+    assert_eq!(conv.convert_signed(i64::MIN), Some(-1));
+
+    // overflow keeps the remainder
+    let mut conv = RateConverter::new(3, 1000).expect("rate");
+    assert_eq!(conv.convert(1), Some(333));
+    assert_eq!(conv.remainder(), 1);
+    assert_eq!(conv.convert(u64::MAX), None);
+    assert_eq!(conv.convert_signed(i64::MIN), None);
+    assert_eq!(conv.convert_signed(i64::MAX), None);
+    assert_eq!(conv.remainder(), 1);
+    assert_eq!(conv.convert(2), Some(667));
+}
@@ -0,0 +1,146 @@
+use map_range_int::resample::{
+    nearest_index, nearest_indices, resample_linear, resample_linear_map, resample_nearest, Align,
+};
+
+fn reference(d: usize, src: usize, dst: usize, align: Align) -> usize {
+    let (d, s, n) = (d as f64, src as f64, dst as f64);
+    let v = match align {
+        Align::Asymmetric => (d * s / n).floor(),
+        Align::HalfPixel => ((d + 0.5) * s / n).floor(),
+        Align::Corners => {
+            if dst == 1 {
+                0.
+            } else {
+                (d * (s - 1.) / (n - 1.)).round()
+            }
+        }
+    };
+    (v as usize).min(src - 1)
+}
+
+#[test]
+fn test_reference() {
+    for align in [Align::Asymmetric, Align::HalfPixel, Align::Corners] {
+        for src in 1..40 {
+            for dst in 1..40 {
+                let idx: Vec<usize> = nearest_indices(src, dst, align).collect();
+                assert_eq!(idx.len(), dst);
+                for (d, s) in idx.iter().enumerate() {
+                    assert_eq!(
+                        *s,
+                        reference(d, src, dst, align),
+                        "{:?} {} {} {}",
+                        align,
+                        src,
+                        dst,
+                        d
+                    );
+                    assert!(*s < src);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_corners() {
+    // first and last line up
+    for src in 1..50 {
+        for dst in 2..50 {
+            assert_eq!(nearest_index(0, src, dst, Align::Corners), Some(0));
+            assert_eq!(
+                nearest_index(dst - 1, src, dst, Align::Corners),
+                Some(src - 1)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_edge() {
+    assert_eq!(nearest_index(3, 10, 3, Align::HalfPixel), None);
+    assert_eq!(nearest_index(0, 0, 3, Align::HalfPixel), None);
+    assert_eq!(nearest_indices(0, 5, Align::Corners).count(), 0);
+    assert_eq!(nearest_indices(5, 0, Align::Corners).count(), 0);
+
+    let mut dst = [7u8; 4];
+    resample_nearest(&[], &mut dst, Align::Asymmetric);
+    assert_eq!(dst, [7; 4]);
+    resample_nearest(&[1, 2], &mut dst, Align::Asymmetric);
+    assert_eq!(dst, [1, 1, 2, 2]);
+
+    // large sizes don't overflow
+    let n = usize::MAX / 4;
+    assert_eq!(nearest_index(n - 1, n, n, Align::HalfPixel), Some(n - 1));
+    assert_eq!(nearest_index(n - 1, n, n, Align::Corners), Some(n - 1));
+}
+
+#[test]
+fn test_linear() {
+    // upsample, endpoints exact
+    let src = [0i16, 300, -300, 1000];
+    let mut dst = [0i16; 7];
+    resample_linear(&src, &mut dst);
+    assert_eq!(dst, [0, 150, 300, 0, -300, 350, 1000]);
+
+    // downsample
+    let src: Vec<u32> = (0..=100).map(|v| v * 10).collect();
+    let mut dst = [0u32; 11];
+    resample_linear(&src, &mut dst);
+    assert_eq!(dst, [0, 100, 200, 300, 400, 500, 600, 700, 800, 900, 1000]);
+
+    // against f64
+    let src = [3u8, 250, 17, 90, 91, 0, 255];
+    for n in 2..60 {
+        let mut dst = vec![0u8; n];
+        let mut dst_f = vec![0f64; n];
+        resample_linear(&src, &mut dst);
+        resample_linear(&src, &mut dst_f);
+        for (a, b) in dst.iter().zip(dst_f.iter()) {
+            assert!((*a as f64 - b).abs() <= 0.5 + 1e-9);
+        }
+        assert_eq!(dst[0], 3);
+        assert_eq!(dst[n - 1], 255);
+    }
+
+    // widening output
+    let mut dst = [0i64; 3];
+    resample_linear(&[i32::MIN, i32::MAX], &mut dst);
+    assert_eq!(dst, [i32::MIN as i64, 0, i32::MAX as i64]);
+    let mut dst = [0u64; 3];
+    resample_linear(&[0, u64::MAX], &mut dst);
+    assert_eq!(dst, [0, u64::MAX / 2 + 1, u64::MAX]);
+}
+
+#[test]
+fn test_linear_edge() {
+    let mut dst = [9u8; 3];
+    resample_linear(&[] as &[u8], &mut dst);
+    assert_eq!(dst, [9; 3]);
+    resample_linear(&[4u8], &mut dst);
+    assert_eq!(dst, [4; 3]);
+    let mut dst = [0u8; 1];
+    resample_linear(&[4u8, 8], &mut dst);
+    assert_eq!(dst, [4]);
+    resample_linear(&[4u8, 8], &mut [] as &mut [u8]);
+}
+
+#[test]
+fn test_linear_map() {
+    // 12 bit ADC to millivolts, out of range samples clamped
+    let src = [0u16, 4095, 5000];
+    let mut dst = [0i32; 5];
+    resample_linear_map(&src, &mut dst, (0, 4095), (-3300, 3300));
+    assert_eq!(dst, [-3300, 0, 3300, 3300, 3300]);
+
+    let mut dst = [0u8; 3];
+    resample_linear_map(&[0., 1.], &mut dst, (0., 1.), (0, 255));
+    assert_eq!(dst, [0, 127, 255]);
+
+    // reversed ranges leave dst alone
+    let mut dst = [7u8; 2];
+    resample_linear_map(&[5u8, 6], &mut dst, (10, 0), (0, 255));
+    assert_eq!(dst, [7, 7]);
+    resample_linear_map(&[5u8, 6], &mut dst, (0, 10), (255, 0));
+    assert_eq!(dst, [7, 7]);
+}
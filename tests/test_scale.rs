@@ -0,0 +1,179 @@
+use map_range_int::scale::{BandScale, LinearScale, PointScale};
+
+fn values(scale: &LinearScale<f64>, count: usize) -> Vec<f64> {
+    scale.ticks(count).map(|(v, _)| v).collect()
+}
+
+#[test]
+fn test_ticks() {
+    let s = LinearScale::new((0., 1.), (0., 100.)).expect("scale");
+    assert_eq!(
+        values(&s, 10),
+        vec![0., 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.]
+    );
+    assert_eq!(s.tick_step(10), 0.1);
+    assert_eq!(s.precision(10), 1);
+    assert_eq!(values(&s, 5), vec![0., 0.2, 0.4, 0.6, 0.8, 1.]);
+    assert_eq!(values(&s, 2), vec![0., 0.5, 1.]);
+    assert_eq!(values(&s, 0), vec![]);
+    assert_eq!(s.precision(40), 2);
+
+    let s = LinearScale::new((-10., 10.), (0., 100.)).expect("scale");
+    assert_eq!(values(&s, 5), vec![-10., -5., 0., 5., 10.]);
+
+    let s = LinearScale::new((1., 1_000_000.), (0., 100.)).expect("scale");
+    assert_eq!(values(&s, 3), vec![500_000., 1_000_000.]);
+    assert_eq!(s.precision(3), 0);
+
+    // reversed domain gives ascending ticks too
+    let s = LinearScale::new((10., -10.), (0., 100.)).expect("scale");
+    assert_eq!(values(&s, 5), vec![-10., -5., 0., 5., 10.]);
+}
+
+#[test]
+fn test_nice() {
+    let s = LinearScale::new((0.201479, 0.996679), (0., 1.))
+        .expect("scale")
+        .nice(10);
+    assert_eq!(s.domain(), (0.2, 1.));
+
+    let s = LinearScale::new((1.1, 10.9), (0., 1.))
+        .expect("scale")
+        .nice(10);
+    assert_eq!(s.domain(), (1., 11.));
+
+    let s = LinearScale::new((10.9, 1.1), (0., 1.))
+        .expect("scale")
+        .nice(10);
+    assert_eq!(s.domain(), (11., 1.));
+
+    let s = LinearScale::new((-0.7, 123.4), (0., 1.))
+        .expect("scale")
+        .nice(5);
+    assert_eq!(s.domain(), (-50., 150.));
+}
+
+#[test]
+fn test_pixels() {
+    let s = LinearScale::new((0., 100.), (0u16, 79)).expect("scale");
+    assert_eq!(s.scale(0.), Some(0));
+    assert_eq!(s.scale(100.), Some(79));
+    assert_eq!(s.scale(50.), Some(39));
+    assert_eq!(s.scale(100.5), None);
+    assert_eq!(s.scale_clamped(100.5), 79);
+    assert_eq!(s.scale_clamped(-3.), 0);
+    assert_eq!(s.invert(79), Some(100.));
+    assert_eq!(s.invert(80), None);
+
+    // both reversed is the same as none
+    let s = LinearScale::new((100., 0.), (79u16, 0)).expect("scale");
+    assert_eq!(s.scale(0.), Some(0));
+    assert_eq!(s.scale(50.), Some(39));
+
+    // ends are exact for awkward domains
+    let s = LinearScale::new((0.1, 0.7), (24i32, -3)).expect("scale");
+    assert_eq!(s.scale(0.1), Some(24));
+    assert_eq!(s.scale(0.7), Some(-3));
+    assert_eq!(s.invert(24), Some(0.1));
+    assert_eq!(s.invert(-3), Some(0.7));
+
+    let ticks: Vec<(f64, i32)> = s.ticks(3).collect();
+    assert_eq!(ticks, vec![(0.2, 19), (0.4, 10), (0.6, 1)]);
+
+    let f = LinearScale::new((0.1, 0.7), (0., 100.)).expect("scale");
+    assert_eq!(f.scale(0.7), Some(100.));
+    assert_eq!(f.invert(100.), Some(0.7));
+    let f = LinearScale::new((0.1, 0.7), (100., 0.)).expect("scale");
+    assert_eq!(f.scale(0.1), Some(100.));
+    assert_eq!(f.invert(100.), Some(0.1));
+
+    assert_eq!(LinearScale::new((1., 1.), (0u8, 10)), None);
+    assert_eq!(LinearScale::new((0., f64::INFINITY), (0u8, 10)), None);
+}
+
+#[test]
+fn test_band() {
+    for width in 0u16..60 {
+        for n in 1..12usize {
+            let s = BandScale::new(n, (5u16, 5 + width)).expect("scale");
+            // no padding: bands fill the range exactly
+            let mut next = 5;
+            let mut widths = Vec::new();
+            for i in 0..n {
+                let (start, w) = s.band(i).expect("band");
+                assert_eq!(start, next);
+                next = start + w;
+                widths.push(w);
+            }
+            assert_eq!(next, 5 + width);
+            let min = widths.iter().min().unwrap();
+            let max = widths.iter().max().unwrap();
+            assert!(max - min <= 1);
+
+            // with padding, still equal and inside
+            let s = s.padding_inner(0.3).padding_outer(0.5);
+            let widths: Vec<u16> = (0..n).map(|i| s.band(i).unwrap().1).collect();
+            let min = widths.iter().min().unwrap();
+            let max = widths.iter().max().unwrap();
+            assert!(max - min <= 1);
+            let (start, w) = s.band(n - 1).unwrap();
+            assert!(start + w <= 5 + width);
+
+            // hit testing
+            for px in 0..70u16 {
+                let hit = (0..n).find(|i| {
+                    let (start, w) = s.band(*i).unwrap();
+                    start <= px && px < start + w
+                });
+                assert_eq!(s.index_at(px), hit);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_band_padding() {
+    // d3: step 100 / (4 - 0.2 + 2 * 0.1) = 25, bandwidth 20
+    let s = BandScale::new(4, (0u32, 100))
+        .expect("scale")
+        .padding_inner(0.2)
+        .padding_outer(0.1);
+    assert_eq!(s.band(0), Some((2, 20)));
+    assert_eq!(s.band(1), Some((27, 20)));
+    assert_eq!(s.band(3), Some((77, 20)));
+    assert_eq!(s.band(4), None);
+
+    let s = BandScale::new(2, (0u8, 10)).expect("scale").padding(1.0);
+    assert_eq!(s.band(0), Some((3, 0)));
+    assert_eq!(s.index_at(3), None);
+
+    assert_eq!(BandScale::new(2, (10u8, 0)), None);
+    let s = BandScale::new(0, (0usize, 10)).expect("scale");
+    assert!(s.is_empty());
+    assert_eq!(s.band(0), None);
+    assert_eq!(s.index_at(0), None);
+}
+
+#[test]
+fn test_point() {
+    let s = PointScale::new(5, (0u16, 100)).expect("scale");
+    let p: Vec<u16> = (0..5).map(|i| s.point(i).unwrap()).collect();
+    assert_eq!(p, vec![0, 25, 50, 75, 100]);
+    assert_eq!(s.nearest(0), Some(0));
+    assert_eq!(s.nearest(12), Some(0));
+    assert_eq!(s.nearest(13), Some(1));
+    assert_eq!(s.nearest(1000), Some(4));
+
+    // d3: step 100 / (3 - 1 + 2 * 0.5) = 33.3
+    let s = PointScale::new(3, (0u16, 100)).expect("scale").padding(0.5);
+    let p: Vec<u16> = (0..3).map(|i| s.point(i).unwrap()).collect();
+    assert_eq!(p, vec![16, 50, 83]);
+
+    let s = PointScale::new(1, (10u8, 20)).expect("scale");
+    assert_eq!(s.point(0), Some(15));
+    assert_eq!(s.nearest(0), Some(0));
+
+    let s = PointScale::new(0, (10u8, 20)).expect("scale");
+    assert_eq!(s.point(0), None);
+    assert_eq!(s.nearest(12), None);
+}
@@ -0,0 +1,76 @@
+use map_range_int::scrollbar::Scrollbar;
+
+#[test]
+fn test_thumb() {
+    let sb = Scrollbar::new(80, 20, 10);
+    assert_eq!(sb.thumb_len(), 2);
+    assert_eq!(sb.thumb(0), (0, 2));
+    assert_eq!(sb.thumb(80), (8, 2));
+    assert_eq!(sb.thumb(1000), (8, 2));
+
+    // all visible
+    let sb = Scrollbar::new(0, 20, 10);
+    assert_eq!(sb.thumb(0), (0, 10));
+    assert_eq!(sb.offset_at(5), 0);
+
+    // nothing at all
+    let sb = Scrollbar::new(0, 0, 10);
+    assert_eq!(sb.thumb(0), (0, 10));
+
+    // no track
+    let sb = Scrollbar::new(100, 10, 0);
+    assert_eq!(sb.thumb(50), (0, 0));
+    assert_eq!(sb.offset_at(0), 0);
+
+    // min thumb larger than the track
+    let sb = Scrollbar::new(100, 10, 4).min_thumb(10);
+    assert_eq!(sb.thumb(50), (0, 4));
+}
+
+#[test]
+fn test_ends() {
+    for max_offset in [1usize, 2, 7, 19, 20, 21, 99, 1000, 1_000_000] {
+        for track in [1usize, 2, 3, 20, 80] {
+            let sb = Scrollbar::new(max_offset, 10, track).min_thumb(2);
+            let (start, len) = sb.thumb(0);
+            assert_eq!(start, 0);
+            let (start, len2) = sb.thumb(max_offset);
+            assert_eq!(len, len2);
+            assert_eq!(start + len, track);
+
+            assert_eq!(sb.offset_at(0), 0);
+            if track > len {
+                assert_eq!(sb.offset_at(track - len), max_offset);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for max_offset in [3usize, 19, 20, 21, 99, 1000, 1_000_000] {
+        let sb = Scrollbar::new(max_offset, 30, 25);
+        let (_, len) = sb.thumb(0);
+        let mut last = 0;
+        for pos in 0..=25 - len {
+            let offset = sb.offset_at(pos);
+            assert!(offset >= last);
+            last = offset;
+            if max_offset >= 25 - len {
+                assert_eq!(sb.thumb(offset).0, pos);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_drag() {
+    let sb = Scrollbar::new(980, 20, 20).min_thumb(4);
+    let (start, _) = sb.thumb(300);
+    // grab the thumb in the middle
+    let grab = 2;
+    let mouse = start + grab;
+    assert_eq!(sb.thumb(sb.drag(mouse, grab)).0, start);
+    assert_eq!(sb.drag(mouse + 100, grab), 980);
+    assert_eq!(sb.drag(0, grab), 0);
+}
@@ -0,0 +1,136 @@
+use map_range_int::slider::Slider;
+
+#[test]
+fn test_float_steps() {
+    let sl = Slider::new((0., 1.), 0.05, 100).expect("valid");
+    assert_eq!(sl.steps(), 20);
+    // walking up doesn't accumulate errors
+    let mut v = 0.;
+    for i in 0..=20 {
+        assert_eq!(v, sl.value_of(i));
+        assert_eq!(sl.index_of(v), i);
+        v = sl.step_up(v);
+    }
+    assert_eq!(v, 1.);
+    assert_eq!(sl.value_of(3), 0.15);
+    assert_eq!(sl.value_of(7), 0.35);
+    assert_eq!(sl.snap(-3.), 0.);
+    assert_eq!(sl.snap(0.174), 0.15);
+    assert_eq!(sl.snap(0.176), 0.2);
+
+    // last step shorter
+    let sl = Slider::new((0f32, 1.1), 0.25, 10).expect("valid");
+    assert_eq!(sl.steps(), 5);
+    assert_eq!(sl.value_of(4), 1.);
+    assert_eq!(sl.value_of(5), 1.1);
+    assert_eq!(sl.snap(1.06), 1.1);
+    assert_eq!(sl.snap(1.04), 1.);
+    assert_eq!(sl.step_up(1.), 1.1);
+
+    assert_eq!(Slider::new((0., 1.), 0., 10), None);
+    assert_eq!(Slider::new((1., 0.), 0.1, 10), None);
+    assert_eq!(Slider::new((0., f64::NAN), 0.1, 10), None);
+}
+
+#[test]
+fn test_f32_steps() {
+    let sl = Slider::new((0f32, 1.), 0.05, 100).expect("valid");
+    assert_eq!(sl.steps(), 20);
+    assert_eq!(sl.snap(0.45), 0.45);
+    assert_eq!(sl.step_up(0.4), 0.45);
+    assert_eq!(sl.value_of(7), 0.35);
+    let mut v = 0.;
+    for i in 0..=20 {
+        assert_eq!(v, sl.value_of(i));
+        assert_eq!(sl.index_of(v), i);
+        v = sl.step_up(v);
+    }
+    assert_eq!(v, 1.);
+
+    let sl = Slider::new((0f32, 1.), 0.1, 100).expect("valid");
+    assert_eq!(sl.steps(), 10);
+    assert_eq!(sl.value_of(3), 0.3);
+    assert_eq!(sl.snap(0.71), 0.7);
+    assert_eq!(sl.step_down(1.), 0.9);
+
+    let sl = Slider::new((0f32, 7.), 0.7, 100).expect("valid");
+    assert_eq!(sl.steps(), 10);
+    assert_eq!(sl.index_of(7.), 10);
+    assert_eq!(sl.step_down(7.), sl.value_of(9));
+    assert!((sl.value_of(9) - 6.3).abs() < 1e-6);
+    assert_eq!(sl.position(sl.value_of(9)), 89);
+    for i in 0..=10 {
+        assert_eq!(sl.index_of(sl.value_of(i)), i);
+    }
+
+    let sl = Slider::new((100.1f32, 100.8), 0.1, 10).expect("valid");
+    assert_eq!(sl.steps(), 7);
+}
+
+#[test]
+fn test_int_steps() {
+    let sl = Slider::new((-10i8, 12), 5, 10).expect("valid");
+    assert_eq!(sl.steps(), 5);
+    assert_eq!(sl.value_of(0), -10);
+    assert_eq!(sl.value_of(4), 10);
+    assert_eq!(sl.value_of(5), 12);
+    assert_eq!(sl.value_of(100), 12);
+    assert_eq!(sl.snap(-8), -10);
+    assert_eq!(sl.snap(-7), -5);
+    assert_eq!(sl.snap(11), 12);
+    assert_eq!(sl.step_down(-10), -10);
+    assert_eq!(sl.step_by(0, -100), -10);
+    assert_eq!(sl.page_up(-10), 12);
+
+    let sl = Slider::new((0u64, u64::MAX), 1, 10).expect("valid");
+    assert_eq!(sl.steps(), u64::MAX);
+    assert_eq!(sl.step_up(u64::MAX), u64::MAX);
+    assert_eq!(sl.page_down(5), 0);
+
+    let sl = Slider::new((i64::MIN, i64::MAX), i64::MAX, 10).expect("valid");
+    assert_eq!(sl.steps(), 3);
+    assert_eq!(sl.value_of(1), -1);
+    assert_eq!(sl.value_of(2), i64::MAX - 1);
+    assert_eq!(sl.value_of(3), i64::MAX);
+
+    // empty range
+    let sl = Slider::new((5u16, 5), 2, 10).expect("valid");
+    assert_eq!(sl.steps(), 0);
+    assert_eq!(sl.index_of(7), 0);
+    assert_eq!(sl.value_at(9), 5);
+    assert_eq!(sl.position(5), 0);
+}
+
+#[test]
+fn test_track() {
+    // more cells than steps: round trip
+    let sl = Slider::new((0u32, 100), 10, 37).expect("valid");
+    for i in 0..=10 {
+        let v = sl.value_of(i);
+        assert_eq!(sl.value_at(sl.position(v)), v);
+    }
+    assert_eq!(sl.position(0), 0);
+    assert_eq!(sl.position(100), 36);
+    assert_eq!(sl.value_at(1000), 100);
+
+    // clicks snap to the closest knob
+    for pos in 0..37 {
+        let v = sl.value_at(pos);
+        let d = sl.position(v).abs_diff(pos);
+        for i in 0..=10 {
+            assert!(d <= sl.position(sl.value_of(i)).abs_diff(pos));
+        }
+    }
+
+    // more steps than cells
+    let sl = Slider::new((0., 1000.), 1., 11).expect("valid");
+    assert_eq!(sl.value_at(0), 0.);
+    assert_eq!(sl.value_at(5), 500.);
+    assert_eq!(sl.value_at(10), 1000.);
+    assert_eq!(sl.position(549.), 5);
+
+    // no track
+    let sl = Slider::new((0., 1.), 0.1, 0).expect("valid");
+    assert_eq!(sl.value_at(3), 0.);
+    assert_eq!(sl.position(1.), 0);
+}
@@ -0,0 +1,61 @@
+use map_range_int::sparkline::{auto_range, level, write_braille, write_sparkline, BLOCKS};
+
+#[test]
+fn test_level() {
+    assert_eq!(level(0u8, (0, 255), 8), 0);
+    assert_eq!(level(31u8, (0, 255), 8), 0);
+    assert_eq!(level(32u8, (0, 255), 8), 1);
+    assert_eq!(level(254u8, (0, 255), 8), 7);
+    assert_eq!(level(255u8, (0, 255), 8), 7);
+
+    // clamped
+    assert_eq!(level(-5i32, (0, 10), 8), 0);
+    assert_eq!(level(50i32, (0, 10), 8), 7);
+    assert_eq!(level(2.5f64, (0., 1.), 4), 3);
+
+    // empty range gives mid-level
+    assert_eq!(level(3u16, (3, 3), 8), 3);
+    assert_eq!(level(0u16, (3, 3), 4), 1);
+    assert_eq!(level(1.5f32, (1.5, 1.5), 8), 3);
+    assert_eq!(level(5u8, (10, 0), 8), 3);
+    assert_eq!(level(5u8, (0, 10), 0), 0);
+}
+
+#[test]
+fn test_auto_range() {
+    assert_eq!(auto_range(&[3i64, -7, 12, 0]), Some((-7, 12)));
+    assert_eq!(auto_range::<u8>(&[]), None);
+}
+
+#[test]
+fn test_write() {
+    let mut s = String::new();
+    write_sparkline(&mut s, &[1u64, 1000, 500], None).expect("write");
+    assert_eq!(s, "▁█▄");
+
+    let mut s = String::new();
+    write_sparkline::<_, u8>(&mut s, &[], None).expect("write");
+    assert_eq!(s, "");
+
+    let mut s = String::new();
+    write_sparkline(&mut s, &[7u8; 4], None).expect("write");
+    assert_eq!(s, BLOCKS[3].to_string().repeat(4));
+
+    // equal values in braille, mid-level is 2 dots
+    let mut s = String::new();
+    write_braille(&mut s, &[7u8; 3], None).expect("write");
+    assert_eq!(s, "\u{28e4}\u{2844}");
+
+    let mut s = String::new();
+    write_braille(&mut s, &[0., 1.], Some((0., 1.))).expect("write");
+    assert_eq!(s, "\u{28f8}");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_alloc() {
+    use map_range_int::sparkline::{braille, sparkline};
+
+    assert_eq!(sparkline(&[0u8, 4, 7], None), "▁▅█");
+    assert_eq!(braille(&[0u8, 3], None), "\u{28f8}");
+}
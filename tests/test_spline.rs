@@ -0,0 +1,91 @@
+use map_range_int::spline::MonotoneCubic;
+
+#[test]
+fn test_new() {
+    assert!(MonotoneCubic::new(&[(0., 0.)]).is_none());
+    assert!(MonotoneCubic::new(&[(0., 0.), (0., 1.)]).is_none());
+    assert!(MonotoneCubic::new(&[(1., 0.), (0., 1.)]).is_none());
+    assert!(MonotoneCubic::new(&[(0f32, 0f32), (1., 1.)]).is_some());
+}
+
+#[test]
+fn test_breakpoints() {
+    let table = [(0., 5.), (10., 7.), (20., 100.), (25., 100.), (40., -3.)];
+    let s = MonotoneCubic::new(&table).expect("valid");
+    for (x, y) in table {
+        assert_eq!(s.map(x), Some(y));
+    }
+    assert_eq!(s.map(-0.1), None);
+    assert_eq!(s.map(40.1), None);
+    assert_eq!(s.map_clamped(-10.), 5.);
+    assert_eq!(s.map_clamped(100.), -3.);
+
+    // flat segment stays flat.
+    for i in 0..=50 {
+        let x = 20. + i as f64 / 10.;
+        assert_eq!(s.map(x), Some(100.));
+    }
+}
+
+#[test]
+fn test_two_points() {
+    let s = MonotoneCubic::new(&[(0f64, 10f64), (10., 20.)]).expect("valid");
+    assert!((s.map(2.5).expect("in_range") - 12.5).abs() < 1e-12);
+}
+
+#[test]
+fn test_monotone() {
+    let table = [
+        (0., 0.),
+        (1., 0.1),
+        (2., 0.2),
+        (3., 5.),
+        (4., 5.1),
+        (10., 5.2),
+        (11., 100.),
+    ];
+    let s = MonotoneCubic::new(&table).expect("valid");
+    let mut last = 0.;
+    for i in 0..=1100 {
+        let y = s.map(i as f64 / 100.).expect("in_range");
+        assert!(y >= last, "{} {} {}", i, y, last);
+        last = y;
+    }
+    assert_eq!(last, 100.);
+}
+
+#[test]
+fn test_bake() {
+    let table = [(0f32, 0f32), (64., 10.), (128., 200.), (255., 255.)];
+    let s = MonotoneCubic::new(&table).expect("valid");
+
+    let mut lut = [0u8; 256];
+    s.bake(&mut lut);
+    assert_eq!(lut[0], 0);
+    assert_eq!(lut[64], 10);
+    assert_eq!(lut[128], 200);
+    assert_eq!(lut[255], 255);
+    assert!(lut.windows(2).all(|w| w[0] <= w[1]));
+
+    // u16 source, entries past the table are clamped.
+    let mut lut = [0u16; 300];
+    s.bake(&mut lut);
+    assert_eq!(lut[299], 255);
+}
+
+#[test]
+fn test_int() {
+    let table = [(0u16, 0u16), (1000, 100), (4095, 65535)];
+    let s = MonotoneCubic::new(&table).expect("valid");
+    assert_eq!(s.map(0), Some(0));
+    assert_eq!(s.map(1000), Some(100));
+    assert_eq!(s.map(4095), Some(65535));
+    assert_eq!(s.map(4096), None);
+
+    let mut last = 0;
+    for x in 0..=4095u16 {
+        let y = s.map(x).expect("in_range");
+        assert!(y >= last);
+        last = y;
+    }
+}
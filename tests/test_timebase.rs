@@ -0,0 +1,79 @@
+use map_range_int::timebase::{rescale, rescale_rnd, Ratio, Rounding, NOPTS};
+
+#[test]
+fn test_rounding() {
+    // 7 / 2 = 3.5, -7 / 2 = -3.5
+    let cases = [
+        (Rounding::ZERO, 3, -3),
+        (Rounding::INF, 4, -4),
+        (Rounding::DOWN, 3, -4),
+        (Rounding::UP, 4, -3),
+        (Rounding::NEAR_INF, 4, -4),
+    ];
+    for (rnd, pos, neg) in cases {
+        assert_eq!(rescale_rnd(7, 1, 2, rnd), pos);
+        assert_eq!(rescale_rnd(-7, 1, 2, rnd), neg);
+        assert_eq!(rescale_rnd(6, 1, 2, rnd), 3);
+        assert_eq!(rescale_rnd(-6, 1, 2, rnd), -3);
+    }
+    // 5 / 3
+    assert_eq!(rescale_rnd(5, 1, 3, Rounding::NEAR_INF), 2);
+    assert_eq!(rescale_rnd(-5, 1, 3, Rounding::NEAR_INF), -2);
+    assert_eq!(rescale_rnd(4, 1, 3, Rounding::NEAR_INF), 1);
+}
+
+#[test]
+fn test_timebase() {
+    let mpeg = Ratio::new(1, 90000);
+    let ms = Ratio::new(1, 1000);
+    let ntsc = Ratio::new(1001, 30000);
+
+    assert_eq!(rescale(3003, mpeg, ntsc, Rounding::NEAR_INF), 1);
+    assert_eq!(rescale(1, ntsc, mpeg, Rounding::NEAR_INF), 3003);
+    assert_eq!(rescale(1, ntsc, ms, Rounding::NEAR_INF), 33);
+    assert_eq!(rescale(1, ntsc, ms, Rounding::UP), 34);
+
+    // no intermediate overflow
+    assert_eq!(
+        rescale(
+            i64::MAX / 2,
+            Ratio::new(1, 1000),
+            Ratio::new(1, 1000),
+            Rounding::ZERO
+        ),
+        i64::MAX / 2
+    );
+    assert_eq!(
+        rescale(i64::MAX / 10, ms, Ratio::new(1, 10000), Rounding::ZERO),
+        i64::MAX / 10 * 10
+    );
+}
+
+#[test]
+fn test_errors() {
+    let ms = Ratio::new(1, 1000);
+    let us = Ratio::new(1, 1_000_000);
+
+    // result too large
+    assert_eq!(rescale(i64::MAX / 2, ms, us, Rounding::ZERO), NOPTS);
+    // invalid timebase
+    assert_eq!(rescale(5, ms, Ratio::new(0, 1), Rounding::ZERO), NOPTS);
+    assert_eq!(rescale(5, ms, Ratio::new(1, -1), Rounding::ZERO), NOPTS);
+    assert_eq!(rescale_rnd(5, -1, 1, Rounding::ZERO), NOPTS);
+
+    // min/max
+    let rnd = Rounding::NEAR_INF.pass_minmax();
+    assert_eq!(rescale(NOPTS, ms, us, rnd), NOPTS);
+    assert_eq!(rescale(i64::MAX, us, ms, rnd), i64::MAX);
+    assert_eq!(
+        rescale(i64::MAX, us, ms, Rounding::NEAR_INF),
+        i64::MAX / 1000 + 1
+    );
+    assert_eq!(
+        rescale(i64::MIN, us, ms, Rounding::NEAR_INF),
+        -(i64::MAX / 1000 + 1)
+    );
+    assert_eq!(rescale(5000, us, ms, rnd), 5);
+    // the flag doesn't carry over to the negated value.
+    assert_eq!(rescale_rnd(-i64::MAX, 1, 2, rnd), -4611686018427387904);
+}
@@ -0,0 +1,91 @@
+use map_range_int::uniform::{uniform_in, uniform_u64};
+
+fn xorshift(mut state: u64) -> impl FnMut() -> u64 {
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    }
+}
+
+macro_rules! check {
+    ($ty:ty, $range:expr) => {
+        let mut rnd = xorshift(0x2545_f491_4f6c_dd1d);
+        let (a, b): ($ty, $ty) = $range;
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        let (mut min, mut max) = (hi, lo);
+        for _ in 0..5000 {
+            let bits = rnd();
+            let v: $ty = uniform_in(bits, $range, &mut rnd);
+            assert!(lo <= v && v <= hi);
+            min = min.min(v);
+            max = max.max(v);
+        }
+        assert_eq!((min, max), (lo, hi));
+    };
+}
+
+#[test]
+fn test_bounds() {
+    check!(u8, (0, 255));
+    check!(u8, (6, 1));
+    check!(i8, (-128, 127));
+    check!(i8, (5, -5));
+    check!(u16, (1000, 1009));
+    check!(i16, (-300, -290));
+    check!(u32, (7, 13));
+    check!(i32, (i32::MAX, i32::MAX - 3));
+    check!(u64, (u64::MAX - 5, u64::MAX));
+    check!(i64, (i64::MIN, i64::MIN + 2));
+    check!(usize, (0, 1));
+    check!(isize, (-1, 1));
+    check!(u32, (9, 9));
+}
+
+#[test]
+fn test_full_range() {
+    let mut none = || -> u64 { panic!("no rejection for the full range") };
+    assert_eq!(uniform_in(12345, (0u64, u64::MAX), &mut none), 12345);
+    assert_eq!(uniform_in(u64::MAX, (u64::MAX, 0u64), &mut none), u64::MAX);
+    assert_eq!(uniform_in(0, (i64::MIN, i64::MAX), &mut none), i64::MIN);
+    assert_eq!(uniform_in(1 << 63, (i64::MIN, i64::MAX), &mut none), 0);
+}
+
+#[test]
+fn test_rejection() {
+    // 2^64 mod 3 == 1, so only a low half of 0 is rejected.
+    let mut calls = 0;
+    let v = uniform_u64(0, 2, || {
+        calls += 1;
+        1 << 63
+    });
+    assert_eq!(v, 1);
+    assert_eq!(calls, 1);
+
+    let mut calls = 0;
+    let v = uniform_u64(1, 2, || {
+        calls += 1;
+        0
+    });
+    assert_eq!(v, 0);
+    assert_eq!(calls, 0);
+
+    // high half is the result.
+    assert_eq!(uniform_u64(u64::MAX, 9, || 0), 9);
+    assert_eq!(uniform_u64(u64::MAX / 2, 9, || 0), 4);
+}
+
+#[test]
+fn test_distribution() {
+    let mut rnd = xorshift(88172645463325252);
+    let mut count = [0u32; 6];
+    for _ in 0..60000 {
+        let bits = rnd();
+        let v: usize = uniform_in(bits, (0, 5), &mut rnd);
+        count[v] += 1;
+    }
+    for c in count {
+        assert!((9500..10500).contains(&c), "{:?}", count);
+    }
+}
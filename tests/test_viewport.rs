@@ -0,0 +1,89 @@
+use map_range_int::viewport::Viewport;
+
+#[test]
+fn test_new() {
+    assert!(Viewport::new((0., 0.), (0., 1.), (0u16, 10), (0u16, 10)).is_none());
+    assert!(Viewport::new((1., 0.), (0., 1.), (0u16, 10), (0u16, 10)).is_none());
+    assert!(Viewport::new((0., 1.), (0., 1.), (10u16, 0), (0u16, 10)).is_none());
+    assert!(Viewport::new((0., 1.), (0., 1.), (5i32, 5), (0i32, 0)).is_some());
+}
+
+#[test]
+fn test_map() {
+    let vp = Viewport::new((-1., 1.), (-1., 1.), (10i32, 29), (100i32, 109)).expect("valid");
+
+    assert_eq!(vp.to_screen(-1., -1.), Some((10, 100)));
+    assert_eq!(vp.to_screen(1., 1.), Some((29, 109)));
+    assert_eq!(vp.to_screen(0., 0.), Some((20, 105)));
+    assert_eq!(vp.to_screen(-0.0001, -0.0001), Some((19, 104)));
+    assert_eq!(vp.to_screen(1.1, 0.), None);
+
+    assert_eq!(vp.to_world(10, 100), Some((-0.95, -0.9)));
+    assert_eq!(vp.to_world(9, 100), None);
+    assert_eq!(vp.to_screen_f(-2., 0.), (0., 105.));
+}
+
+#[test]
+fn test_round_trip() {
+    let mut vp = Viewport::new((-3.7, 12.1), (0.3, 1.9), (0u16, 199), (0u16, 59))
+        .expect("valid")
+        .flip_y(true);
+
+    for _ in 0..2 {
+        for sx in 0..=199u16 {
+            for sy in 0..=59u16 {
+                let (x, y) = vp.to_world(sx, sy).expect("on_screen");
+                assert_eq!(vp.to_screen(x, y), Some((sx, sy)));
+            }
+        }
+        vp.zoom_at(17, 33, 3.3);
+    }
+}
+
+#[test]
+fn test_flip() {
+    let vp = Viewport::new((0., 10.), (0., 10.), (0u16, 9), (0u16, 9))
+        .expect("valid")
+        .flip_y(true);
+    assert_eq!(vp.to_screen(0., 0.), Some((0, 9)));
+    assert_eq!(vp.to_screen(0., 10.), Some((0, 0)));
+    assert_eq!(vp.to_screen(0., 0.5), Some((0, 9)));
+    // rounds down in screen coordinates.
+    assert_eq!(vp.to_screen(0., 1.0), Some((0, 9)));
+    assert_eq!(vp.to_screen(0., 1.01), Some((0, 8)));
+    assert_eq!(vp.to_world(0, 0), Some((0.5, 9.5)));
+}
+
+#[test]
+fn test_zoom_pan() {
+    let mut vp = Viewport::new((0., 100.), (0., 100.), (0i32, 99), (0i32, 99)).expect("valid");
+
+    let before = vp.to_world(70, 10).expect("on_screen");
+    vp.zoom_at(70, 10, 4.);
+    let after = vp.to_world(70, 10).expect("on_screen");
+    assert!((before.0 - after.0).abs() < 1e-12);
+    assert!((before.1 - after.1).abs() < 1e-12);
+    assert!((vp.scale().0 - 0.25).abs() < 1e-12);
+
+    // zoom out again
+    vp.zoom_at(70, 10, 0.25);
+    assert!((vp.world_x().0 - 0.).abs() < 1e-9);
+    assert!((vp.world_x().1 - 100.).abs() < 1e-9);
+
+    // content follows the mouse
+    let p = vp.to_world(50, 50).expect("on_screen");
+    vp.pan(5, -3);
+    let (x, y) = vp.to_world(55, 47).expect("on_screen");
+    assert!((x - p.0).abs() < 1e-9 && (y - p.1).abs() < 1e-9);
+
+    let mut vp = vp.flip_y(true);
+    let p = vp.to_world(50, 50).expect("on_screen");
+    vp.pan(5, -3);
+    let (x, y) = vp.to_world(55, 47).expect("on_screen");
+    assert!((x - p.0).abs() < 1e-9 && (y - p.1).abs() < 1e-9);
+
+    // ignored
+    vp.zoom_at(1, 1, 0.);
+    vp.zoom_at(1, 1, f64::NAN);
+    assert!((vp.scale().0 - 1.).abs() < 1e-9);
+}
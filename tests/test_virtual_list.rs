@@ -0,0 +1,78 @@
+use map_range_int::virtual_list::VirtualList;
+
+fn heights(n: usize) -> impl Iterator<Item = usize> {
+    (0..n).map(|i| (i * 7 + 3) % 5)
+}
+
+#[test]
+fn test_lookup() {
+    let n = 1000;
+    let mut storage: Vec<usize> = heights(n).collect();
+    let list = VirtualList::from_heights(&mut storage);
+    assert_eq!(list.len(), n);
+
+    let mut offset = 0;
+    for (i, h) in heights(n).enumerate() {
+        assert_eq!(list.height(i), h);
+        assert_eq!(list.offset_of(i), offset);
+        for k in 0..h {
+            assert_eq!(list.item_at(offset + k), Some((i, k)));
+        }
+        offset += h;
+    }
+    assert_eq!(list.total_height(), offset);
+    assert_eq!(list.offset_of(n), offset);
+    assert_eq!(list.item_at(offset), None);
+
+    let mut empty: [usize; 0] = [];
+    let list = VirtualList::from_heights(&mut empty);
+    assert!(list.is_empty());
+    assert_eq!(list.item_at(0), None);
+    assert_eq!(list.visible(0, 10), 0..0);
+}
+
+#[test]
+fn test_update() {
+    let mut storage = vec![0; 1_000_000];
+    let mut list = VirtualList::new(&mut storage);
+    assert_eq!(list.total_height(), 0);
+    for i in (0..1_000_000).step_by(1000) {
+        list.set_height(i, 2);
+    }
+    assert_eq!(list.total_height(), 2000);
+    assert_eq!(list.item_at(1999), Some((999_000, 1)));
+
+    list.set_height(0, 0);
+    list.set_height(999_999, 5);
+    assert_eq!(list.total_height(), 2003);
+    assert_eq!(list.item_at(0), Some((1000, 0)));
+    assert_eq!(list.item_at(1998), Some((999_999, 0)));
+    assert_eq!(list.height(999_999), 5);
+}
+
+#[test]
+fn test_scroll() {
+    let mut storage = [1usize, 3, 1, 2, 1, 6, 1];
+    let list = VirtualList::from_heights(&mut storage);
+    assert_eq!(list.visible(0, 4), 0..2);
+    assert_eq!(list.visible(2, 4), 1..4);
+    assert_eq!(list.visible(14, 4), 6..7);
+    assert_eq!(list.visible(3, 0), 1..1);
+    assert_eq!(list.max_offset(4), 11);
+
+    // already visible
+    assert_eq!(list.scroll_to(2, 1, 4), 1);
+    // above
+    assert_eq!(list.scroll_to(1, 3, 4), 1);
+    // below, align the end
+    assert_eq!(list.scroll_to(3, 0, 4), 3);
+    // larger than the page, show the start
+    assert_eq!(list.scroll_to(5, 0, 4), 8);
+    // clamped
+    assert_eq!(list.scroll_to(6, 0, 4), 11);
+
+    let sb = list.scrollbar(4, 15);
+    assert_eq!(sb.max_offset(), 11);
+    assert_eq!(sb.thumb(0), (0, 4));
+    assert_eq!(sb.thumb(11), (11, 4));
+}